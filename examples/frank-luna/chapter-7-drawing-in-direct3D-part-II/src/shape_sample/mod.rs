@@ -214,7 +214,7 @@ impl DxSample for ShapesSample {
         {
             let event = Event::create(false, false).unwrap();
             base.fence
-                .set_event_on_completion(curr_frame_resource.fence, event)
+                .set_event_on_completion(curr_frame_resource.fence, &event)
                 .unwrap();
             event.wait(u32::MAX);
             event.close().unwrap();
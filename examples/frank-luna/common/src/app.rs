@@ -458,7 +458,7 @@ impl Base {
         if self.fence.get_completed_value() < self.current_fence {
             let event = Event::create(false, false).unwrap();
             self.fence
-                .set_event_on_completion(self.current_fence, event)
+                .set_event_on_completion(self.current_fence, &event)
                 .unwrap();
             event.wait(u32::MAX);
             event.close().unwrap();
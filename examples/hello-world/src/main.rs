@@ -486,7 +486,7 @@ fn wait_for_previous_frame(resources: &mut Resources) {
     if resources.fence.get_completed_value() < fence {
         resources
             .fence
-            .set_event_on_completion(fence, resources.fence_event)
+            .set_event_on_completion(fence, &resources.fence_event)
             .ok()
             .unwrap();
 
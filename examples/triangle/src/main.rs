@@ -0,0 +1,408 @@
+//! Renders a colored triangle.
+//!
+//! Flags:
+//! * `-warp` renders with the WARP adapter;
+//! * `-frames N` renders `N` frames into a hidden window and exits, which is how CI runs the example.
+
+use std::num::NonZeroIsize;
+
+use oxidx::dx::*;
+
+use winit::{
+    dpi::PhysicalSize,
+    event::{Event as WinitEvent, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    raw_window_handle::{HasWindowHandle, RawWindowHandle},
+    window::WindowBuilder,
+};
+
+const FRAME_COUNT: usize = 2;
+const WIDTH: u32 = 1280;
+const HEIGHT: u32 = 720;
+
+const SHADERS: &str = r#"
+struct PSInput
+{
+    float4 position : SV_POSITION;
+    float4 color : COLOR;
+};
+
+PSInput VSMain(float4 position : POSITION, float4 color : COLOR)
+{
+    PSInput result;
+
+    result.position = position;
+    result.color = color;
+
+    return result;
+}
+
+float4 PSMain(PSInput input) : SV_TARGET
+{
+    return input.color;
+}
+"#;
+
+struct CommandLine {
+    use_warp_device: bool,
+    frames: Option<usize>,
+}
+
+fn parse_command_line() -> CommandLine {
+    let mut use_warp_device = false;
+    let mut frames = None;
+
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        if arg.eq_ignore_ascii_case("-warp") || arg.eq_ignore_ascii_case("/warp") {
+            use_warp_device = true;
+        } else if arg.eq_ignore_ascii_case("-frames") || arg.eq_ignore_ascii_case("/frames") {
+            frames = args.next().and_then(|n| n.parse().ok());
+        }
+    }
+
+    CommandLine {
+        use_warp_device,
+        frames,
+    }
+}
+
+#[repr(C)]
+struct Vertex {
+    position: [f32; 3],
+    color: [f32; 4],
+}
+
+struct Renderer {
+    device: Device,
+    command_queue: CommandQueue,
+    swap_chain: Swapchain3,
+    frame_index: u32,
+    render_targets: [Resource; FRAME_COUNT],
+    rtv_heap: DescriptorHeap,
+    rtv_descriptor_size: usize,
+    viewport: Viewport,
+    scissor_rect: Rect,
+    command_allocator: CommandAllocator,
+    command_list: GraphicsCommandList,
+    root_signature: RootSignature,
+    pso: PipelineState,
+
+    #[allow(dead_code)]
+    vertex_buffer: Resource,
+
+    vbv: VertexBufferView,
+    fence: Fence,
+    fence_value: u64,
+    fence_event: Event,
+}
+
+impl Renderer {
+    fn new(command_line: &CommandLine, hwnd: NonZeroIsize) -> Self {
+        let factory_flags = if cfg!(debug_assertions) {
+            create_debug().unwrap().enable_debug_layer();
+            FactoryCreationFlags::Debug
+        } else {
+            FactoryCreationFlags::empty()
+        };
+
+        let factory = create_factory4(factory_flags).unwrap();
+
+        let device = if command_line.use_warp_device {
+            let adapter = factory.enum_warp_adapters().unwrap();
+            create_device(Some(&adapter), FeatureLevel::Level11).unwrap()
+        } else {
+            create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap()
+        };
+
+        let command_queue: CommandQueue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+
+        let swap_chain_desc = SwapchainDesc1::new(WIDTH, HEIGHT)
+            .with_buffer_count(FRAME_COUNT)
+            .with_format(Format::Bgra8Unorm)
+            .with_usage(FrameBufferUsage::RenderTargetOutput)
+            .with_swap_effect(SwapEffect::FlipDiscard);
+
+        let swap_chain: Swapchain3 = factory
+            .create_swapchain_for_hwnd(
+                &command_queue,
+                hwnd,
+                &swap_chain_desc,
+                None,
+                None::<&Output1>,
+            )
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        factory
+            .make_window_association(hwnd, WindowAssociationFlags::NoAltEnter)
+            .unwrap();
+
+        let rtv_heap: DescriptorHeap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(FRAME_COUNT))
+            .unwrap();
+        let rtv_descriptor_size =
+            device.get_descriptor_handle_increment_size(DescriptorHeapType::Rtv);
+        let rtv_handle = rtv_heap.get_cpu_descriptor_handle_for_heap_start();
+
+        let render_targets: [Resource; FRAME_COUNT] = std::array::from_fn(|i| {
+            let render_target: Resource = swap_chain.get_buffer(i).unwrap();
+            device.create_render_target_view(
+                Some(&render_target),
+                None,
+                rtv_handle.offset(i * rtv_descriptor_size),
+            );
+
+            render_target
+        });
+
+        let command_allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+
+        let root_signature = create_root_signature(&device);
+        let pso = create_pipeline_state(&device, &root_signature);
+
+        let command_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &command_allocator, Some(&pso))
+            .unwrap();
+        command_list.close().unwrap();
+
+        let (vertex_buffer, vbv) = create_vertex_buffer(&device, WIDTH as f32 / HEIGHT as f32);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        let fence_event = Event::create(false, false).unwrap();
+
+        Self {
+            device,
+            command_queue,
+            frame_index: swap_chain.get_current_back_buffer_index(),
+            swap_chain,
+            render_targets,
+            rtv_heap,
+            rtv_descriptor_size,
+            viewport: Viewport::from_size((WIDTH as f32, HEIGHT as f32)),
+            scissor_rect: Rect::default().with_size((WIDTH as i32, HEIGHT as i32)),
+            command_allocator,
+            command_list,
+            root_signature,
+            pso,
+            vertex_buffer,
+            vbv,
+            fence,
+            fence_value: 1,
+            fence_event,
+        }
+    }
+
+    fn render(&mut self) {
+        self.populate_command_list();
+
+        self.command_queue
+            .execute_command_lists(&[Some(self.command_list.clone())]);
+
+        if let Err(err) = self.swap_chain.present(1, PresentFlags::empty()) {
+            panic!(
+                "Present failed: {err}, device removed reason: {:?}",
+                self.device.get_device_removed_reason()
+            );
+        }
+
+        self.wait_for_gpu();
+        self.frame_index = self.swap_chain.get_current_back_buffer_index();
+    }
+
+    fn populate_command_list(&self) {
+        self.command_allocator.reset().unwrap();
+
+        let command_list = &self.command_list;
+        let render_target = &self.render_targets[self.frame_index as usize];
+
+        command_list
+            .reset(&self.command_allocator, Some(&self.pso))
+            .unwrap();
+
+        command_list.set_graphics_root_signature(Some(&self.root_signature));
+        command_list.rs_set_viewports(&[self.viewport]);
+        command_list.rs_set_scissor_rects(&[self.scissor_rect]);
+
+        command_list.resource_barrier(&[ResourceBarrier::transition(
+            render_target,
+            ResourceStates::Present,
+            ResourceStates::RenderTarget,
+            None,
+        )]);
+
+        let rtv_handle = self
+            .rtv_heap
+            .get_cpu_descriptor_handle_for_heap_start()
+            .offset(self.frame_index as usize * self.rtv_descriptor_size);
+
+        command_list.om_set_render_targets(&[rtv_handle], false, None);
+        command_list.clear_render_target_view(rtv_handle, [0.0, 0.2, 0.4, 1.0], &[]);
+        command_list.ia_set_primitive_topology(PrimitiveTopology::Triangle);
+        command_list.ia_set_vertex_buffers(0, &[self.vbv]);
+        command_list.draw_instanced(3, 1, 0, 0);
+
+        command_list.resource_barrier(&[ResourceBarrier::transition(
+            render_target,
+            ResourceStates::RenderTarget,
+            ResourceStates::Present,
+            None,
+        )]);
+
+        command_list.close().unwrap();
+    }
+
+    fn wait_for_gpu(&mut self) {
+        let fence_value = self.fence_value;
+
+        self.command_queue.signal(&self.fence, fence_value).unwrap();
+        self.fence_value += 1;
+
+        if self.fence.get_completed_value() < fence_value {
+            self.fence
+                .set_event_on_completion(fence_value, &self.fence_event)
+                .unwrap();
+            self.fence_event.wait(u32::MAX);
+        }
+    }
+}
+
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        self.wait_for_gpu();
+    }
+}
+
+fn create_root_signature(device: &Device) -> RootSignature {
+    let desc =
+        RootSignatureDesc::default().with_flags(RootSignatureFlags::AllowInputAssemblerInputLayout);
+
+    device
+        .serialize_and_create_root_signature(&desc, RootSignatureVersion::V1_0, 0)
+        .unwrap()
+}
+
+fn create_pipeline_state(device: &Device, root_signature: &RootSignature) -> PipelineState {
+    let compile_flags = if cfg!(debug_assertions) {
+        COMPILE_DEBUG | COMPILE_SKIP_OPT
+    } else {
+        0
+    };
+
+    let vertex_shader =
+        Blob::compile_from_source(SHADERS, &[], c"VSMain", c"vs_5_0", compile_flags, 0).unwrap();
+    let pixel_shader =
+        Blob::compile_from_source(SHADERS, &[], c"PSMain", c"ps_5_0", compile_flags, 0).unwrap();
+
+    let input_element_descs = [
+        InputElementDesc::per_vertex(SemanticName::Position(0), Format::Rgb32Float, 0),
+        InputElementDesc::per_vertex(SemanticName::Color(0), Format::Rgba32Float, 0),
+    ];
+
+    let desc = GraphicsPipelineDesc::new(&vertex_shader)
+        .with_root_signature(root_signature)
+        .with_input_layout(&input_element_descs)
+        .with_ps(&pixel_shader)
+        .with_rasterizer_state(
+            RasterizerDesc::default()
+                .with_cull_mode(CullMode::None)
+                .with_fill_mode(FillMode::Solid),
+        )
+        .with_primitive_topology(PipelinePrimitiveTopology::Triangle)
+        .with_render_targets([Format::Bgra8Unorm]);
+
+    device.create_graphics_pipeline(&desc).unwrap()
+}
+
+fn create_vertex_buffer(device: &Device, aspect_ratio: f32) -> (Resource, VertexBufferView) {
+    let vertices = [
+        Vertex {
+            position: [0.0, 0.25 * aspect_ratio, 0.0],
+            color: [1.0, 0.0, 0.0, 1.0],
+        },
+        Vertex {
+            position: [0.25, -0.25 * aspect_ratio, 0.0],
+            color: [0.0, 1.0, 0.0, 1.0],
+        },
+        Vertex {
+            position: [-0.25, -0.25 * aspect_ratio, 0.0],
+            color: [0.0, 0.0, 1.0, 1.0],
+        },
+    ];
+
+    let vertex_buffer: Resource = device
+        .create_committed_resource(
+            &HeapProperties::upload(),
+            HeapFlags::empty(),
+            &ResourceDesc::buffer(std::mem::size_of_val(&vertices)),
+            ResourceStates::GenericRead,
+            None,
+        )
+        .unwrap();
+
+    unsafe {
+        let data = vertex_buffer.map::<Vertex>(0, None).unwrap();
+        std::ptr::copy_nonoverlapping(vertices.as_ptr(), data.as_ptr(), vertices.len());
+        vertex_buffer.unmap(0, None);
+    }
+
+    let vbv = VertexBufferView::new(
+        vertex_buffer.get_gpu_virtual_address(),
+        std::mem::size_of::<Vertex>(),
+        std::mem::size_of_val(&vertices),
+    );
+
+    (vertex_buffer, vbv)
+}
+
+fn main() {
+    let command_line = parse_command_line();
+
+    let mut title = String::from("D3D12 Triangle");
+
+    if command_line.use_warp_device {
+        title.push_str(" (WARP)");
+    }
+
+    let event_loop = EventLoop::new().unwrap();
+    event_loop.set_control_flow(ControlFlow::Poll);
+
+    let window = WindowBuilder::new()
+        .with_inner_size(PhysicalSize::new(WIDTH, HEIGHT))
+        .with_title(title)
+        .with_resizable(false)
+        .with_visible(command_line.frames.is_none())
+        .build(&event_loop)
+        .unwrap();
+
+    let RawWindowHandle::Win32(handle) = window.window_handle().unwrap().as_raw() else {
+        panic!("Unsupported OS");
+    };
+
+    let mut renderer = Renderer::new(&command_line, handle.hwnd);
+    let mut rendered = 0;
+
+    event_loop
+        .run(|event, target| match event {
+            WinitEvent::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                ..
+            } => target.exit(),
+            WinitEvent::AboutToWait => {
+                renderer.render();
+                rendered += 1;
+
+                if command_line.frames.is_some_and(|frames| rendered >= frames) {
+                    target.exit();
+                }
+            }
+            _ => (),
+        })
+        .unwrap();
+}
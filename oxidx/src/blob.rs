@@ -4,7 +4,7 @@ use windows::{
     core::{Interface, HSTRING, PCSTR},
     Win32::Graphics::{
         Direct3D::{
-            Fxc::{D3DCompileFromFile, D3DCreateBlob, D3DReflect},
+            Fxc::{D3DCompile, D3DCompileFromFile, D3DCreateBlob, D3DReflect},
             ID3DBlob, ID3DInclude,
         },
         Direct3D12::{ID3D12ShaderReflection, D3D12_CACHED_PIPELINE_STATE, D3D12_SHADER_BYTECODE},
@@ -46,6 +46,21 @@ pub trait IBlobExt: IBlob {
     where
         Self: Sized;
 
+    /// Compiles Microsoft High Level Shader Language (HLSL) source code into bytecode for a given target.
+    /// `#include` directives are not supported.
+    ///
+    /// For more information: [`D3DCompile function`](https://learn.microsoft.com/en-us/windows/win32/api/d3dcompiler/nf-d3dcompiler-d3dcompile)
+    fn compile_from_source(
+        source: impl AsRef<str>,
+        defines: &[ShaderMacro],
+        entry_point: impl AsRef<CStr>,
+        target: impl AsRef<CStr>,
+        flags1: u32,
+        flags2: u32,
+    ) -> Result<Self, DxError>
+    where
+        Self: Sized;
+
     /// Create buffer
     ///
     /// For more information: [`D3DCreateBlob function`](https://learn.microsoft.com/en-us/windows/win32/api/d3dcompiler/nf-d3dcompiler-d3dcreateblob)
@@ -160,6 +175,68 @@ impl_trait! {
         Ok(Blob::new(shader.unwrap()))
     }
 
+    fn compile_from_source(
+        source: impl AsRef<str>,
+        defines: &[ShaderMacro],
+        entry_point: impl AsRef<CStr>,
+        target: impl AsRef<CStr>,
+        flags1: u32,
+        flags2: u32,
+    ) -> Result<Self, DxError>
+    where
+        Self: Sized,
+    {
+        let source = source.as_ref();
+        let entry_point = PCSTR::from_raw(entry_point.as_ref().as_ptr() as *const _);
+        let target = PCSTR::from_raw(target.as_ref().as_ptr() as *const _);
+
+        let mut shader = None;
+
+        let defines = if !defines.is_empty() {
+            Some(defines.as_ptr() as *const _)
+        } else {
+            None
+        };
+
+        let mut error_msg = None;
+
+        unsafe {
+            let res = D3DCompile(
+                source.as_ptr() as *const _,
+                source.len(),
+                PCSTR::null(),
+                defines,
+                None::<&ID3DInclude>,
+                entry_point,
+                target,
+                flags1,
+                flags2,
+                &mut shader,
+                Some(&mut error_msg),
+            )
+            .map_err(DxError::from);
+
+            if let Err(err) = res {
+                let Some(error_msg) = error_msg else {
+                    return Err(err);
+                };
+
+                let pointer = error_msg.GetBufferPointer() as *mut u8;
+                let size = error_msg.GetBufferSize();
+
+                let slice = std::slice::from_raw_parts(pointer, size);
+
+                return Err(DxError::ShaderCompilationError(
+                    std::str::from_utf8(slice)
+                        .unwrap_or_default()
+                        .to_string())
+                );
+            }
+        }
+
+        Ok(Blob::new(shader.unwrap()))
+    }
+
     fn create_blob(size: usize) -> Result<Self, DxError>
     where
         Self: Sized
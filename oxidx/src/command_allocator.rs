@@ -1,9 +1,13 @@
 use windows::{
-    core::{Interface, Param},
-    Win32::Graphics::Direct3D12::ID3D12CommandAllocator,
+    core::{Interface, Param, GUID},
+    Win32::Graphics::Direct3D12::{ID3D12CommandAllocator, D3D12_COMMAND_LIST_TYPE},
 };
 
-use crate::{create_type, error::DxError, impl_trait, HasInterface};
+use crate::{create_type, error::DxError, impl_trait, types::CommandListType, HasInterface};
+
+/// Private data slot holding the type an allocator was created with, since D3D12 has no getter for it.
+pub(crate) const COMMAND_LIST_TYPE_GUID: GUID =
+    GUID::from_u128(0x4f3b9c1e_82a7_4d65_b0e3_7a19c5d2e864);
 
 /// Represents the allocations of storage for graphics processing unit (GPU) commands.
 ///
@@ -11,6 +15,11 @@ use crate::{create_type, error::DxError, impl_trait, HasInterface};
 pub trait ICommandAllocator:
     for<'a> HasInterface<Raw: Interface, RawRef<'a>: Param<ID3D12CommandAllocator>>
 {
+    /// Gets the type of the command lists the allocator can record, as given to [`IDevice::create_command_allocator`](crate::device::IDevice::create_command_allocator).
+    ///
+    /// Returns [`None`] if the allocator wasn't created by this crate, so the type wasn't recorded.
+    fn command_list_type(&self) -> Option<CommandListType>;
+
     /// Indicates to re-use the memory that is associated with the command allocator.
     ///
     /// For more information: [`ID3D12CommandAllocator::Reset method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12commandallocator-reset)
@@ -28,9 +37,50 @@ impl_trait! {
     impl ICommandAllocator =>
     CommandAllocator;
 
+    fn command_list_type(&self) -> Option<CommandListType> {
+        unsafe {
+            let mut r#type = D3D12_COMMAND_LIST_TYPE::default();
+            let mut size = std::mem::size_of::<D3D12_COMMAND_LIST_TYPE>() as u32;
+
+            self.0.GetPrivateData(
+                &COMMAND_LIST_TYPE_GUID,
+                &mut size,
+                Some(&mut r#type as *mut _ as *mut _)
+            ).ok()?;
+
+            Some(r#type.into())
+        }
+    }
+
     fn reset(&self) -> Result<(), DxError> {
         unsafe {
             self.0.Reset().map_err(DxError::from)
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn command_list_type_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let allocator = device
+            .create_command_allocator(CommandListType::Compute)
+            .unwrap();
+        let cmd_list = device
+            .create_command_list(0, CommandListType::Compute, &allocator, PSO_NONE)
+            .unwrap();
+
+        assert_eq!(
+            allocator.command_list_type(),
+            Some(CommandListType::Compute)
+        );
+        assert_eq!(cmd_list.get_type(), CommandListType::Compute);
+
+        let result = device.create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE);
+        assert!(matches!(result, Err(DxError::InvalidArgs)));
+    }
+}
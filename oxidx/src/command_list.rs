@@ -1,5 +1,6 @@
 use std::{ffi::CStr, ops::Range};
 
+use smallvec::SmallVec;
 use windows::{
     core::{Interface, Param, PCSTR},
     Win32::{Foundation::BOOL, Graphics::Direct3D12::*},
@@ -15,10 +16,10 @@ use crate::{
     ext::memcpy_subresource,
     impl_trait,
     pix::WIN_PIX_EVENT_RUNTIME,
-    pso::IPipelineState,
+    pso::{IPipelineState, IStateObject},
     query_heap::IQueryHeap,
-    resources::IResource,
-    root_signature::IRootSignature,
+    resources::{IResource, Resource},
+    root_signature::{IRootSignature, RootSignature},
     types::*,
     HasInterface,
 };
@@ -43,6 +44,8 @@ pub trait IGraphicsCommandList:
     ICommandList + for<'a> HasInterface<RawRef<'a>: Param<ID3D12GraphicsCommandList>>
 {
     /// Marks the start of a user-defined region of work.
+    ///
+    /// Regions may nest, each one must be closed with [`IGraphicsCommandList::end_event`] before the list is closed.
     fn begin_event(&self, color: impl Into<u64>, label: impl AsRef<CStr>);
 
     /// Starts a query running.
@@ -79,6 +82,10 @@ pub trait IGraphicsCommandList:
 
     /// Sets all of the elements in an unordered-access view (UAV) to the specified f32 values.
     ///
+    /// The view is passed twice: `view_gpu_handle_in_current_heap` must point into the shader-visible heap
+    /// currently set with [`IGraphicsCommandList::set_descriptor_heaps`], and `view_cpu_handle` must point to
+    /// the same view in a non-shader-visible heap, which the driver reads on the CPU.
+    ///
     /// For more information: [`ID3D12GraphicsCommandList::ClearUnorderedAccessViewFloat method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-clearunorderedaccessviewfloat)
     fn clear_unordered_access_view_f32(
         &self,
@@ -91,6 +98,9 @@ pub trait IGraphicsCommandList:
 
     /// Sets all of the elements in an unordered-access view (UAV) to the specified u32 values.
     ///
+    /// Takes the same pair of handles as [`IGraphicsCommandList::clear_unordered_access_view_f32`].
+    /// Raw and structured buffers are cleared with the first value only.
+    ///
     /// For more information: [`ID3D12GraphicsCommandList::ClearUnorderedAccessViewUint method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-clearunorderedaccessviewuint)
     fn clear_unordered_access_view_u32(
         &self,
@@ -101,6 +111,11 @@ pub trait IGraphicsCommandList:
         rects: &[Rect],
     );
 
+    /// Unbinds the vertex buffers of `num_slots` input slots starting at `start_slot`.
+    ///
+    /// Useful when switching to an input layout which doesn't read those slots, to keep the debug layer from warning about stale bindings.
+    fn clear_vertex_buffers(&self, start_slot: u32, num_slots: u32);
+
     /// Indicates that recording to the command list has finished.
     ///
     /// For more information: [`ID3D12GraphicsCommandList::Close method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-close)
@@ -136,6 +151,18 @@ pub trait IGraphicsCommandList:
         src_box: Option<&DxBox>,
     );
 
+    /// Copies the whole subresource `src_sub` of `src` into the subresource `dst_sub` of `dst`.
+    ///
+    /// Both subresources must have the same dimensions and compatible formats. Equivalent to [`IGraphicsCommandList::copy_texture_region`]
+    /// with subresource index locations, a zero destination offset and no source box.
+    fn copy_whole_subresource(
+        &self,
+        dst: &impl IResource,
+        dst_sub: u32,
+        src: &impl IResource,
+        src_sub: u32,
+    );
+
     /// Copies tiles from buffer to tiled resource or vice versa.
     ///
     /// For more information: [`ID3D12GraphicsCommandList::CopyTiles method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-copytiles)
@@ -187,7 +214,7 @@ pub trait IGraphicsCommandList:
         start_instance_location: u32,
     );
 
-    /// Marks the end of a user-defined region of work.
+    /// Marks the end of the innermost region opened with [`IGraphicsCommandList::begin_event`].
     fn end_event(&self);
 
     /// Ends a running query.
@@ -202,12 +229,16 @@ pub trait IGraphicsCommandList:
 
     /// Apps perform indirect draws/dispatches using the ExecuteIndirect method.
     ///
+    /// Executes up to `max_command_count` commands laid out as `command_signature` describes, starting at `argument_buffer_offset`.
+    /// With a `count_buffer` the actual count is the smaller of `max_command_count` and the `u32` read from it at `count_buffer_offset`.
+    /// Both buffers must be in a state including [`ResourceStates::IndirectArgument`].
+    ///
     /// For more information: [`ID3D12GraphicsCommandList::ExecuteIndirect method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-executeindirect)
     fn execute_indirect(
         &self,
         command_signature: &impl ICommandSignature,
         max_command_count: u32,
-        argument_buffer: impl IResource,
+        argument_buffer: &impl IResource,
         argument_buffer_offset: u64,
         count_buffer: Option<&impl IResource>,
         count_buffer_offset: u64,
@@ -215,6 +246,8 @@ pub trait IGraphicsCommandList:
 
     /// Sets the view for the index buffer.
     ///
+    /// `None` unbinds the index buffer.
+    ///
     /// For more information: [`ID3D12GraphicsCommandList::IASetIndexBuffer method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-iasetindexbuffer)
     fn ia_set_index_buffer(&self, view: Option<&IndexBufferView>);
 
@@ -225,6 +258,8 @@ pub trait IGraphicsCommandList:
 
     /// Sets a CPU descriptor handle for the vertex buffers.
     ///
+    /// An empty `buffers` slice binds nothing, use [`IGraphicsCommandList::clear_vertex_buffers`] to unbind slots.
+    ///
     /// For more information: [`ID3D12GraphicsCommandList::IASetVertexBuffers method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-iasetvertexbuffers)
     fn ia_set_vertex_buffers(&self, slot: u32, buffers: &[VertexBufferView]);
 
@@ -248,6 +283,20 @@ pub trait IGraphicsCommandList:
     /// For more information: [`ID3D12GraphicsCommandList::OMSetStencilRef method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-omsetstencilref)
     fn om_set_stencil_ref(&self, stencil_ref: u32);
 
+    /// Sets separate stencil reference values for front and back facing primitives.
+    ///
+    /// `options` is queried once with [`IDevice::check_feature_support`] and gates the call on
+    /// [`Options14Feature::independent_front_and_back_stencil_ref_mask_supported`](features::Options14Feature::independent_front_and_back_stencil_ref_mask_supported).
+    /// Without support `front` is used for both faces, and debug builds add a warning to the info queue when the values differ.
+    ///
+    /// For more information: [`ID3D12GraphicsCommandList8::OMSetFrontAndBackStencilRef method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist8-omsetfrontandbackstencilref)
+    fn om_set_front_and_back_stencil_ref(
+        &self,
+        options: &features::Options14Feature,
+        front: u32,
+        back: u32,
+    );
+
     /// Resets a command list back to its initial state as if a new command list was just created.
     ///
     /// For more information: [`ID3D12GraphicsCommandList::Reset method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-reset)
@@ -296,6 +345,11 @@ pub trait IGraphicsCommandList:
     /// For more information: [`ID3D12GraphicsCommandList::RSSetViewports method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-rssetviewports)
     fn rs_set_viewports(&self, viewport: &[Viewport]);
 
+    /// Opens a region like [`IGraphicsCommandList::begin_event`], which is closed when the returned guard is dropped.
+    fn scoped_event(&self, color: impl Into<u64>, label: impl AsRef<CStr>) -> EventScope<'_, Self>
+    where
+        Self: Sized;
+
     /// Sets a constant in the compute root signature.
     ///
     /// For more information: [`ID3D12GraphicsCommandList::SetComputeRoot32BitConstant method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-setcomputeroot32bitconstant)
@@ -325,9 +379,19 @@ pub trait IGraphicsCommandList:
         buffer_location: GpuVirtualAddress,
     );
 
+    /// Same as [`IGraphicsCommandList::set_compute_root_constant_buffer_view`] with the constants at `offset` bytes into `resource`.
+    ///
+    /// The resulting address must be aligned to [`CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT`].
+    fn set_compute_root_constant_buffer_view_at(
+        &self,
+        root_parameter_index: u32,
+        resource: &impl IResource,
+        offset: u64,
+    );
+
     /// Sets a descriptor table into the compute root signature.
     ///
-    /// For more information: [`ID3D12GraphicsCommandList::SetComputeRootDescriptorTable method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nn-d3d12-id3d12graphicscommandlist)
+    /// For more information: [`ID3D12GraphicsCommandList::SetComputeRootDescriptorTable method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-setcomputerootdescriptortable)
     fn set_compute_root_descriptor_table(
         &self,
         root_parameter_index: u32,
@@ -391,6 +455,16 @@ pub trait IGraphicsCommandList:
         buffer_location: GpuVirtualAddress,
     );
 
+    /// Same as [`IGraphicsCommandList::set_graphics_root_constant_buffer_view`] with the constants at `offset` bytes into `resource`.
+    ///
+    /// The resulting address must be aligned to [`CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT`].
+    fn set_graphics_root_constant_buffer_view_at(
+        &self,
+        root_parameter_index: u32,
+        resource: &impl IResource,
+        offset: u64,
+    );
+
     /// Sets a descriptor table into the graphics root signature.
     ///
     /// For more information: [`ID3D12GraphicsCommandList::SetGraphicsRootDescriptorTable method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist-setgraphicsrootdescriptortable)
@@ -447,6 +521,62 @@ pub trait IGraphicsCommandList:
     fn so_set_targets(&self, start_slot: u32, views: Option<&[StreamOutputBufferView]>);
 }
 
+/// Encapsulates a list of graphics commands for rendering, extending the interface to support writing immediate values directly to a buffer.
+///
+/// For more information: [`ID3D12GraphicsCommandList2 interface`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nn-d3d12-id3d12graphicscommandlist2)
+pub trait IGraphicsCommandList2: IGraphicsCommandList {
+    /// Writes a number of 32-bit immediate values to the specified buffer locations directly from the command stream.
+    ///
+    /// Supported command list types are reported by [`Options3Feature::write_buffer_immediate_support_flags`](crate::types::features::Options3Feature::write_buffer_immediate_support_flags).
+    /// If `modes` is provided it must have the same length as `params`.
+    ///
+    /// For more information: [`ID3D12GraphicsCommandList2::WriteBufferImmediate method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist2-writebufferimmediate)
+    fn write_buffer_immediate(
+        &self,
+        params: &[WriteBufferImmediateParameter],
+        modes: Option<&[WriteBufferImmediateMode]>,
+    );
+}
+
+/// Encapsulates a list of graphics commands for rendering, extending the interface to support raytracing and state objects.
+///
+/// For more information: [`ID3D12GraphicsCommandList4 interface`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nn-d3d12-id3d12graphicscommandlist4)
+pub trait IGraphicsCommandList4: IGraphicsCommandList2 {
+    /// Launches the threads of a ray generation shader.
+    ///
+    /// Requires a raytracing pipeline set with [`IGraphicsCommandList4::set_pipeline_state1`] and
+    /// [`Options5Feature::raytracing_tier`](crate::types::features::Options5Feature::raytracing_tier) other than [`RaytracingTier::NotSupported`].
+    ///
+    /// For more information: [`ID3D12GraphicsCommandList4::DispatchRays method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist4-dispatchrays)
+    fn dispatch_rays(&self, desc: &DispatchRaysDesc);
+
+    /// Sets a state object on the command list, as needed for raytracing and work graph dispatches.
+    ///
+    /// Replaces any pipeline state set with [`IGraphicsCommandList::set_pipeline_state`].
+    ///
+    /// For more information: [`ID3D12GraphicsCommandList4::SetPipelineState1 method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist4-setpipelinestate1)
+    fn set_pipeline_state1(&self, state_object: &impl IStateObject);
+}
+
+/// Encapsulates a list of graphics commands for rendering, extending the interface to support variable-rate shading (VRS).
+///
+/// For more information: [`ID3D12GraphicsCommandList5 interface`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nn-d3d12-id3d12graphicscommandlist5)
+pub trait IGraphicsCommandList5: IGraphicsCommandList4 {
+    /// Sets the base shading rate, and combiners, for variable-rate shading (VRS).
+    ///
+    /// Requires [`Options6Feature::variable_shading_rate_tier`](crate::types::features::Options6Feature::variable_shading_rate_tier) to be at least [`VariableShadingRateTier::Tier1`].
+    ///
+    /// For more information: [`ID3D12GraphicsCommandList5::RSSetShadingRate method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist5-rssetshadingrate)
+    fn rs_set_shading_rate(&self, base: ShadingRate, combiners: [ShadingRateCombiner; 2]);
+
+    /// Sets the screen-space shading-rate image for variable-rate shading (VRS).
+    ///
+    /// Requires [`VariableShadingRateTier::Tier2`].
+    ///
+    /// For more information: [`ID3D12GraphicsCommandList5::RSSetShadingRateImage method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12graphicscommandlist5-rssetshadingrateimage)
+    fn rs_set_shading_rate_image(&self, image: Option<&impl IResource>);
+}
+
 pub trait IGraphicsCommandListExt: IGraphicsCommandList {
     fn update_subresources_raw<T: Clone>(
         &self,
@@ -485,9 +615,33 @@ pub trait IGraphicsCommandListExt: IGraphicsCommandList {
 
 create_type! { GraphicsCommandList wrap ID3D12GraphicsCommandList }
 
+create_type! {
+    /// Encapsulates a list of graphics commands for rendering, extending the interface to support writing immediate values directly to a buffer.
+    ///
+    /// For more information: [`ID3D12GraphicsCommandList2 interface`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nn-d3d12-id3d12graphicscommandlist2)
+    GraphicsCommandList2 wrap ID3D12GraphicsCommandList2; decorator for GraphicsCommandList
+}
+
+create_type! {
+    /// Encapsulates a list of graphics commands for rendering, extending the interface to support raytracing and state objects.
+    ///
+    /// For more information: [`ID3D12GraphicsCommandList4 interface`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nn-d3d12-id3d12graphicscommandlist4)
+    GraphicsCommandList4 wrap ID3D12GraphicsCommandList4; decorator for GraphicsCommandList, GraphicsCommandList2
+}
+
+create_type! {
+    /// Encapsulates a list of graphics commands for rendering, extending the interface to support variable-rate shading (VRS).
+    ///
+    /// For more information: [`ID3D12GraphicsCommandList5 interface`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nn-d3d12-id3d12graphicscommandlist5)
+    GraphicsCommandList5 wrap ID3D12GraphicsCommandList5; decorator for GraphicsCommandList, GraphicsCommandList2, GraphicsCommandList4
+}
+
 impl_trait! {
     impl ICommandList =>
-    GraphicsCommandList;
+    GraphicsCommandList,
+    GraphicsCommandList2,
+    GraphicsCommandList4,
+    GraphicsCommandList5;
 
     fn get_type(&self) -> CommandListType {
         unsafe {
@@ -498,7 +652,10 @@ impl_trait! {
 
 impl_trait! {
     impl IGraphicsCommandList =>
-    GraphicsCommandList;
+    GraphicsCommandList,
+    GraphicsCommandList2,
+    GraphicsCommandList4,
+    GraphicsCommandList5;
 
     fn begin_event(&self, color: impl Into<u64>, label: impl AsRef<CStr>) {
         unsafe {
@@ -613,6 +770,20 @@ impl_trait! {
         }
     }
 
+    fn clear_vertex_buffers(&self, start_slot: u32, num_slots: u32) {
+        unsafe {
+            // The generated wrapper derives the count from the slice, so a null range has to go through the vtable.
+            let list: &ID3D12GraphicsCommandList = &self.0;
+
+            (Interface::vtable(list).IASetVertexBuffers)(
+                Interface::as_raw(list),
+                start_slot,
+                num_slots,
+                std::ptr::null(),
+            );
+        }
+    }
+
     fn close(&self) -> Result<(), DxError> {
         unsafe {
             self.0.Close().map_err(DxError::from)
@@ -670,6 +841,19 @@ impl_trait! {
         }
     }
 
+    fn copy_whole_subresource(
+        &self,
+        dst: &impl IResource,
+        dst_sub: u32,
+        src: &impl IResource,
+        src_sub: u32,
+    ) {
+        let dst = TextureCopyLocation::subresource(dst, dst_sub);
+        let src = TextureCopyLocation::subresource(src, src_sub);
+
+        self.copy_texture_region(&dst, 0, 0, 0, &src, None);
+    }
+
     fn copy_tiles(
         &self,
         tiled_resource: &impl IResource,
@@ -776,7 +960,7 @@ impl_trait! {
         &self,
         command_signature: &impl ICommandSignature,
         max_command_count: u32,
-        argument_buffer: impl IResource,
+        argument_buffer: &impl IResource,
         argument_buffer_offset: u64,
         count_buffer: Option<&impl IResource>,
         count_buffer_offset: u64,
@@ -876,6 +1060,36 @@ impl_trait! {
         }
     }
 
+    fn om_set_front_and_back_stencil_ref(
+        &self,
+        options: &features::Options14Feature,
+        front: u32,
+        back: u32,
+    ) {
+        unsafe {
+            if options.independent_front_and_back_stencil_ref_mask_supported() {
+                if let Ok(list) = self.0.cast::<ID3D12GraphicsCommandList8>() {
+                    list.OMSetFrontAndBackStencilRef(front, back);
+                    return;
+                }
+            }
+
+            #[cfg(debug_assertions)]
+            if front != back {
+                let mut queue: Option<ID3D12InfoQueue> = None;
+
+                if let (Ok(()), Some(queue)) = (self.0.GetDevice(&mut queue), queue) {
+                    let _ = queue.AddApplicationMessage(
+                        D3D12_MESSAGE_SEVERITY_WARNING,
+                        windows::core::s!("OMSetFrontAndBackStencilRef is not supported, the front stencil reference is used for both faces"),
+                    );
+                }
+            }
+
+            self.0.OMSetStencilRef(front);
+        }
+    }
+
     fn reset(
         &self,
         command_allocator: &impl ICommandAllocator,
@@ -966,6 +1180,12 @@ impl_trait! {
         }
     }
 
+    fn scoped_event(&self, color: impl Into<u64>, label: impl AsRef<CStr>) -> EventScope<'_, Self> {
+        self.begin_event(color, label);
+
+        EventScope(self)
+    }
+
     fn set_compute_root_32bit_constant<T: Copy>(
         &self,
         root_parameter_index: u32,
@@ -1018,6 +1238,21 @@ impl_trait! {
         }
     }
 
+    fn set_compute_root_constant_buffer_view_at(
+        &self,
+        root_parameter_index: u32,
+        resource: &impl IResource,
+        offset: u64,
+    ) {
+        let buffer_location = resource.get_gpu_virtual_address() + offset;
+        debug_assert!(
+            buffer_location.is_multiple_of(CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT as u64),
+            "constant buffer view address must be 256-byte aligned"
+        );
+
+        self.set_compute_root_constant_buffer_view(root_parameter_index, buffer_location);
+    }
+
     fn set_compute_root_descriptor_table(
         &self,
         root_parameter_index: u32,
@@ -1132,6 +1367,21 @@ impl_trait! {
         }
     }
 
+    fn set_graphics_root_constant_buffer_view_at(
+        &self,
+        root_parameter_index: u32,
+        resource: &impl IResource,
+        offset: u64,
+    ) {
+        let buffer_location = resource.get_gpu_virtual_address() + offset;
+        debug_assert!(
+            buffer_location.is_multiple_of(CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT as u64),
+            "constant buffer view address must be 256-byte aligned"
+        );
+
+        self.set_graphics_root_constant_buffer_view(root_parameter_index, buffer_location);
+    }
+
     fn set_graphics_root_descriptor_table(
         &self,
         root_parameter_index: u32,
@@ -1237,7 +1487,10 @@ impl_trait! {
 
 impl_trait! {
     impl IGraphicsCommandListExt =>
-    GraphicsCommandList;
+    GraphicsCommandList,
+    GraphicsCommandList2,
+    GraphicsCommandList4,
+    GraphicsCommandList5;
 
     fn update_subresources_raw<T: Clone>(
         &self,
@@ -1391,3 +1644,1520 @@ impl_trait! {
         )
     }
 }
+
+impl_trait! {
+    impl IGraphicsCommandList2 =>
+    GraphicsCommandList2,
+    GraphicsCommandList4,
+    GraphicsCommandList5;
+
+    fn write_buffer_immediate(
+        &self,
+        params: &[WriteBufferImmediateParameter],
+        modes: Option<&[WriteBufferImmediateMode]>,
+    ) {
+        if let Some(modes) = modes {
+            assert_eq!(params.len(), modes.len(), "Expected a mode for every parameter");
+        }
+
+        unsafe {
+            self.0.WriteBufferImmediate(
+                params.len() as u32,
+                params.as_ptr() as *const _,
+                modes.map(|m| m.as_ptr() as *const _)
+            );
+        }
+    }
+}
+
+impl_trait! {
+    impl IGraphicsCommandList4 =>
+    GraphicsCommandList4,
+    GraphicsCommandList5;
+
+    fn dispatch_rays(&self, desc: &DispatchRaysDesc) {
+        unsafe {
+            self.0.DispatchRays(&desc.0);
+        }
+    }
+
+    fn set_pipeline_state1(&self, state_object: &impl IStateObject) {
+        unsafe {
+            self.0.SetPipelineState1(state_object.as_raw_ref());
+        }
+    }
+}
+
+impl_trait! {
+    impl IGraphicsCommandList5 =>
+    GraphicsCommandList5;
+
+    fn rs_set_shading_rate(&self, base: ShadingRate, combiners: [ShadingRateCombiner; 2]) {
+        unsafe {
+            self.0.RSSetShadingRate(base.as_raw(), Some(combiners.as_ptr() as *const _));
+        }
+    }
+
+    fn rs_set_shading_rate_image(&self, image: Option<&impl IResource>) {
+        unsafe {
+            if let Some(image) = image {
+                self.0.RSSetShadingRateImage(image.as_raw_ref());
+            } else {
+                self.0.RSSetShadingRateImage(None);
+            }
+        }
+    }
+}
+
+/// Accumulates resource barriers and submits them with a single [`IGraphicsCommandList::resource_barrier`] call.
+#[derive(Clone, Default)]
+pub struct BarrierBatch<'a>(SmallVec<[ResourceBarrier<'a>; 8]>);
+
+impl<'a> BarrierBatch<'a> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a transition of all subresources of the resource.
+    #[inline]
+    pub fn transition(
+        &mut self,
+        resource: &'a Resource,
+        before: ResourceStates,
+        after: ResourceStates,
+    ) -> &mut Self {
+        self.0
+            .push(ResourceBarrier::transition(resource, before, after, None));
+        self
+    }
+
+    #[inline]
+    pub fn uav(&mut self, resource: &'a Resource) -> &mut Self {
+        self.0.push(ResourceBarrier::uav(resource));
+        self
+    }
+
+    #[inline]
+    pub fn aliasing(&mut self, before: &'a Resource, after: &'a Resource) -> &mut Self {
+        self.0.push(ResourceBarrier::aliasing(before, after));
+        self
+    }
+
+    #[inline]
+    pub fn push(&mut self, barrier: ResourceBarrier<'a>) -> &mut Self {
+        self.0.push(barrier);
+        self
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Submits all recorded barriers to the command list and clears the batch.
+    pub fn flush(&mut self, cmd_list: &impl IGraphicsCommandList) {
+        if self.0.is_empty() {
+            return;
+        }
+
+        cmd_list.resource_barrier(&self.0);
+        self.0.clear();
+    }
+}
+
+/// Wraps a command list, remembers the descriptor heaps bound through it and skips redundant
+/// [`IGraphicsCommandList::set_descriptor_heaps`] calls.
+///
+/// The command list must be reset with [`DescriptorHeapCache::reset`], which also forgets the bound heaps.
+/// Binding heaps on [`DescriptorHeapCache::cmd_list`] directly leaves the cache out of date.
+#[derive(Clone, Debug)]
+pub struct DescriptorHeapCache<L: IGraphicsCommandList> {
+    cmd_list: L,
+    heaps: SmallVec<[Option<DescriptorHeap>; 2]>,
+}
+
+impl<L: IGraphicsCommandList> DescriptorHeapCache<L> {
+    #[inline]
+    pub fn new(cmd_list: L) -> Self {
+        Self {
+            cmd_list,
+            heaps: SmallVec::new(),
+        }
+    }
+
+    /// The wrapped command list, to record everything but descriptor heap binds.
+    #[inline]
+    pub fn cmd_list(&self) -> &L {
+        &self.cmd_list
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.cmd_list
+    }
+
+    /// Binds `descriptor_heaps` unless they are already bound, returns whether the command list was called.
+    pub fn set(&mut self, descriptor_heaps: &[Option<DescriptorHeap>]) -> bool {
+        if self.heaps.as_slice() == descriptor_heaps {
+            return false;
+        }
+
+        self.cmd_list.set_descriptor_heaps(descriptor_heaps);
+        self.heaps = descriptor_heaps.iter().cloned().collect();
+
+        true
+    }
+
+    /// Resets the command list with [`IGraphicsCommandList::reset`] and forgets the bound heaps.
+    pub fn reset(
+        &mut self,
+        command_allocator: &impl ICommandAllocator,
+        pso: Option<&impl IPipelineState>,
+    ) -> Result<(), DxError> {
+        self.cmd_list.reset(command_allocator, pso)?;
+        self.heaps.clear();
+
+        Ok(())
+    }
+}
+
+/// Wraps a command list, remembers the root signatures bound through it and skips binding the same one again.
+///
+/// Like [`DescriptorHeapCache`], the command list must be reset with [`RootSignatureCache::reset`],
+/// and binding root signatures on [`RootSignatureCache::cmd_list`] directly leaves the cache out of date.
+#[derive(Clone, Debug)]
+pub struct RootSignatureCache<L: IGraphicsCommandList> {
+    cmd_list: L,
+    graphics: Option<RootSignature>,
+    compute: Option<RootSignature>,
+}
+
+impl<L: IGraphicsCommandList> RootSignatureCache<L> {
+    #[inline]
+    pub fn new(cmd_list: L) -> Self {
+        Self {
+            cmd_list,
+            graphics: None,
+            compute: None,
+        }
+    }
+
+    /// The wrapped command list, to record everything but root signature binds.
+    #[inline]
+    pub fn cmd_list(&self) -> &L {
+        &self.cmd_list
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.cmd_list
+    }
+
+    /// Binds `root_signature` as the graphics root signature unless it already is, returns whether the command list was called.
+    pub fn set_graphics(&mut self, root_signature: &RootSignature) -> bool {
+        if self.graphics.as_ref() == Some(root_signature) {
+            return false;
+        }
+
+        self.cmd_list
+            .set_graphics_root_signature(Some(root_signature));
+        self.graphics = Some(root_signature.clone());
+
+        true
+    }
+
+    /// Binds `root_signature` as the compute root signature unless it already is, returns whether the command list was called.
+    pub fn set_compute(&mut self, root_signature: &RootSignature) -> bool {
+        if self.compute.as_ref() == Some(root_signature) {
+            return false;
+        }
+
+        self.cmd_list
+            .set_compute_root_signature(Some(root_signature));
+        self.compute = Some(root_signature.clone());
+
+        true
+    }
+
+    /// Resets the command list with [`IGraphicsCommandList::reset`] and forgets the bound root signatures.
+    pub fn reset(
+        &mut self,
+        command_allocator: &impl ICommandAllocator,
+        pso: Option<&impl IPipelineState>,
+    ) -> Result<(), DxError> {
+        self.cmd_list.reset(command_allocator, pso)?;
+        self.graphics = None;
+        self.compute = None;
+
+        Ok(())
+    }
+}
+
+/// Guard returned by [`IGraphicsCommandList::scoped_event`].
+///
+/// Calls [`IGraphicsCommandList::end_event`] on drop, so the region can't be left open on an early return.
+#[must_use = "the event is closed as soon as the guard is dropped"]
+pub struct EventScope<'a, L: IGraphicsCommandList>(&'a L);
+
+impl<L: IGraphicsCommandList> Drop for EventScope<'_, L> {
+    fn drop(&mut self) {
+        self.0.end_event();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use windows::{core::Interface, Win32::Graphics::Direct3D12::ID3D12InfoQueue};
+
+    use crate::{dx::*, HasInterface};
+
+    #[test]
+    fn barrier_batch_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        let texture = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(64, 64)
+                    .with_format(Format::Rgba8Unorm)
+                    .with_flags(ResourceFlags::AllowRenderTarget),
+                ResourceStates::Common,
+                None,
+            )
+            .unwrap();
+        let buffer = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(256).with_flags(ResourceFlags::AllowUnorderedAccess),
+                ResourceStates::Common,
+                None,
+            )
+            .unwrap();
+
+        let mut batch = BarrierBatch::new();
+        batch
+            .transition(
+                &texture,
+                ResourceStates::Common,
+                ResourceStates::RenderTarget,
+            )
+            .transition(
+                &buffer,
+                ResourceStates::Common,
+                ResourceStates::UnorderedAccess,
+            )
+            .uav(&buffer);
+
+        assert_eq!(batch.len(), 3);
+
+        batch.flush(&cmd_list);
+
+        assert!(batch.is_empty());
+        assert!(cmd_list.close().is_ok());
+    }
+
+    #[test]
+    fn write_buffer_immediate_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut options = features::Options3Feature::default();
+        device.check_feature_support(&mut options).unwrap();
+
+        if !options
+            .write_buffer_immediate_support_flags()
+            .contains(CommandListSupportFlags::Direct)
+        {
+            return;
+        }
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList2 = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        let readback = device
+            .create_committed_resource(
+                &HeapProperties::readback(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(8),
+                ResourceStates::CopyDest,
+                None,
+            )
+            .unwrap();
+        let va = readback.get_gpu_virtual_address();
+
+        cmd_list.write_buffer_immediate(
+            &[
+                WriteBufferImmediateParameter::new(va, 0xDEAD),
+                WriteBufferImmediateParameter::new(va + 4, 0xBEEF),
+            ],
+            Some(&[
+                WriteBufferImmediateMode::MarkerIn,
+                WriteBufferImmediateMode::MarkerOut,
+            ]),
+        );
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        let ptr = readback.map::<u32>(0, Some(0..8)).unwrap();
+        let values = unsafe { std::slice::from_raw_parts(ptr.as_ptr(), 2) };
+
+        assert_eq!(values, [0xDEAD, 0xBEEF]);
+
+        readback.unmap(0, Some(0..0));
+    }
+
+    #[test]
+    fn rs_set_shading_rate_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut options = features::Options6Feature::default();
+        device.check_feature_support(&mut options).unwrap();
+
+        if options.variable_shading_rate_tier() == VariableShadingRateTier::NotSupported {
+            return;
+        }
+
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList5 = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        cmd_list.rs_set_shading_rate(ShadingRate::Rate2x2, [ShadingRateCombiner::Passthrough; 2]);
+        cmd_list.close().unwrap();
+
+        assert!(device.get_device_removed_reason().is_ok());
+    }
+
+    #[test]
+    fn copy_tiles_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut options = features::OptionsFeature::default();
+        device.check_feature_support(&mut options).unwrap();
+
+        if options.tiled_resources_tier() == TiledResourcesTier::NotSupported {
+            return;
+        }
+
+        let texture = device
+            .create_reserved_resource(
+                &ResourceDesc::texture_2d(256, 256)
+                    .with_format(Format::Rgba8Unorm)
+                    .with_layout(TextureLayout::UndefinedSwizzle64Kb),
+                ResourceStates::CopySource,
+                None,
+            )
+            .unwrap();
+        let buffer = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(TILED_RESOURCE_TILE_SIZE_IN_BYTES as usize),
+                ResourceStates::CopyDest,
+                None,
+            )
+            .unwrap();
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        cmd_list.copy_tiles(
+            &texture,
+            &TiledResourceCoordinate::new(0, 0, 0, 0),
+            &TileRegionSize::default().with_tiles(1),
+            &buffer,
+            0,
+            TileCopyFlags::SwizzledTiledResourceToLinearBuffer,
+        );
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        assert!(device.get_device_removed_reason().is_ok());
+    }
+
+    #[test]
+    fn root_shader_resource_view_test() {
+        const SHADERS: &str = r#"
+            StructuredBuffer<float4> positions : register(t0);
+            RWByteAddressBuffer counter : register(u0);
+
+            float4 VSMain(uint id : SV_VertexID) : SV_POSITION { return positions[id]; }
+            float4 PSMain() : SV_TARGET { return float4(1, 0, 0, 1); }
+        "#;
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let parameters = [RootParameter::srv(0, 0), RootParameter::uav(0, 0)];
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default().with_parameters(&parameters),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+
+        let vs = Blob::compile_from_source(SHADERS, &[], c"VSMain", c"vs_5_0", 0, 0).unwrap();
+        let ps = Blob::compile_from_source(SHADERS, &[], c"PSMain", c"ps_5_0", 0, 0).unwrap();
+        let pso = device
+            .create_graphics_pipeline(
+                &GraphicsPipelineDesc::new(&vs)
+                    .with_ps(&ps)
+                    .with_root_signature(&root_signature)
+                    .with_primitive_topology(PipelinePrimitiveTopology::Triangle)
+                    .with_render_targets([Format::Rgba8Unorm]),
+            )
+            .unwrap();
+
+        let positions = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(3 * 16),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+        let counter = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(256).with_flags(ResourceFlags::AllowUnorderedAccess),
+                ResourceStates::UnorderedAccess,
+                None,
+            )
+            .unwrap();
+        let render_target = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(64, 64)
+                    .with_format(Format::Rgba8Unorm)
+                    .with_flags(ResourceFlags::AllowRenderTarget),
+                ResourceStates::RenderTarget,
+                None,
+            )
+            .unwrap();
+
+        let rtv_heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(1))
+            .unwrap();
+        let rtv = rtv_heap.get_cpu_descriptor_handle_for_heap_start();
+        device.create_render_target_view(Some(&render_target), None, rtv);
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, Some(&pso))
+            .unwrap();
+
+        cmd_list.set_graphics_root_signature(Some(&root_signature));
+        cmd_list.set_graphics_root_shader_resource_view(0, positions.get_gpu_virtual_address());
+        cmd_list.set_graphics_root_unordered_access_view(1, counter.get_gpu_virtual_address());
+        cmd_list.rs_set_viewports(&[Viewport::from_size((64.0, 64.0))]);
+        cmd_list.rs_set_scissor_rects(&[Rect::default().with_size((64, 64))]);
+        cmd_list.om_set_render_targets(&[rtv], false, None);
+        cmd_list.ia_set_primitive_topology(PrimitiveTopology::Triangle);
+        cmd_list.draw_instanced(3, 1, 0, 0);
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        assert!(device.get_device_removed_reason().is_ok());
+    }
+
+    #[test]
+    fn nested_events_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        cmd_list.begin_event(0xff0000ffu32, c"frame");
+        cmd_list.begin_event(0xff00ff00u32, c"draw");
+        cmd_list.set_marker(0xffff0000u32, c"triangle");
+        cmd_list.ia_set_primitive_topology(PrimitiveTopology::Triangle);
+        cmd_list.draw_instanced(3, 1, 0, 0);
+        cmd_list.end_event();
+        cmd_list.end_event();
+
+        assert!(cmd_list.close().is_ok());
+    }
+
+    #[test]
+    fn index_buffer_view_test() {
+        let view = IndexBufferView::new(0x1000, 24, Format::R32Uint);
+        assert_eq!(view.buffer_location(), 0x1000);
+        assert_eq!(view.size_in_bytes(), 24);
+        assert_eq!(view.format(), Format::R32Uint);
+
+        let view = IndexBufferView::from_count(0x1000, 6, Format::R32Uint);
+        assert_eq!(view.size_in_bytes(), 24);
+
+        let view = IndexBufferView::from_count(0, 6, Format::R16Uint);
+        assert_eq!(view.size_in_bytes(), 12);
+    }
+
+    #[test]
+    fn clear_uav_counter_test() {
+        const SIZE: usize = 64;
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let (upload, mut mapped) = unsafe { device.create_upload_buffer(SIZE as u64) }.unwrap();
+        mapped.as_mut_slice().fill(0xff);
+        drop(mapped);
+
+        let counter = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(SIZE).with_flags(ResourceFlags::AllowUnorderedAccess),
+                ResourceStates::CopyDest,
+                None,
+            )
+            .unwrap();
+        let readback = device
+            .create_committed_resource(
+                &HeapProperties::readback(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(SIZE),
+                ResourceStates::CopyDest,
+                None,
+            )
+            .unwrap();
+
+        let uav = UnorderedAccessViewDesc::buffer(
+            Format::R32Typeless,
+            0..SIZE / 4,
+            0,
+            0,
+            BufferUavFlags::Raw,
+        );
+
+        let gpu_heap = device
+            .create_descriptor_heap(
+                &DescriptorHeapDesc::cbr_srv_uav(1).with_flags(DescriptorHeapFlags::ShaderVisible),
+            )
+            .unwrap();
+        let cpu_heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::cbr_srv_uav(1))
+            .unwrap();
+        device.create_unordered_access_view(
+            Some(&counter),
+            RES_NONE,
+            Some(&uav),
+            gpu_heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+        device.create_unordered_access_view(
+            Some(&counter),
+            RES_NONE,
+            Some(&uav),
+            cpu_heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        cmd_list.copy_buffer_region(&counter, 0, &upload, 0, SIZE);
+        cmd_list.resource_barrier(&[ResourceBarrier::transition(
+            &counter,
+            ResourceStates::CopyDest,
+            ResourceStates::UnorderedAccess,
+            None,
+        )]);
+        cmd_list.set_descriptor_heaps(&[Some(gpu_heap.clone())]);
+        cmd_list.clear_unordered_access_view_u32(
+            gpu_heap.get_gpu_descriptor_handle_for_heap_start(),
+            cpu_heap.get_cpu_descriptor_handle_for_heap_start(),
+            &counter,
+            [0; 4],
+            &[],
+        );
+        cmd_list.resource_barrier(&[ResourceBarrier::transition(
+            &counter,
+            ResourceStates::UnorderedAccess,
+            ResourceStates::CopySource,
+            None,
+        )]);
+        cmd_list.copy_buffer_region(&readback, 0, &counter, 0, SIZE);
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        let data = readback.map::<u8>(0, Some(0..SIZE)).unwrap();
+        let data = unsafe { std::slice::from_raw_parts(data.as_ptr(), SIZE) };
+        assert!(data.iter().all(|byte| *byte == 0));
+        readback.unmap(0, Some(0..0));
+    }
+
+    #[test]
+    fn aliasing_barrier_test() {
+        const SIZE: usize = 64 * 1024;
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let heap = device
+            .create_heap(
+                &HeapDesc::new(SIZE, HeapProperties::default())
+                    .with_flags(HeapFlags::AllowOnlyBuffers),
+            )
+            .unwrap();
+        let create_placed = || {
+            device
+                .create_placed_resource(
+                    &heap,
+                    0,
+                    &ResourceDesc::buffer(SIZE),
+                    ResourceStates::CopyDest,
+                    None,
+                )
+                .unwrap()
+        };
+        let first = create_placed();
+        let second = create_placed();
+
+        let source = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(SIZE),
+                ResourceStates::CopySource,
+                None,
+            )
+            .unwrap();
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        cmd_list.copy_buffer_region(&first, 0, &source, 0, SIZE);
+        cmd_list.resource_barrier(&[ResourceBarrier::aliasing(&first, &second)]);
+        cmd_list.copy_buffer_region(&second, 0, &source, 0, SIZE);
+        cmd_list.resource_barrier(&[ResourceBarrier::aliasing_any()]);
+        cmd_list.copy_buffer_region(&first, 0, &source, 0, SIZE);
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+    }
+
+    #[test]
+    fn descriptor_heap_cache_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        let heap = device
+            .create_descriptor_heap(
+                &DescriptorHeapDesc::cbr_srv_uav(1).with_flags(DescriptorHeapFlags::ShaderVisible),
+            )
+            .unwrap();
+        let heaps = [Some(heap)];
+
+        let mut cache = DescriptorHeapCache::new(cmd_list);
+        assert!(cache.set(&heaps));
+        assert!(!cache.set(&heaps));
+
+        cache.cmd_list().close().unwrap();
+        allocator.reset().unwrap();
+        cache.reset(&allocator, PSO_NONE).unwrap();
+
+        assert!(cache.set(&heaps));
+        assert!(cache.into_inner().close().is_ok());
+    }
+
+    #[test]
+    fn execute_indirect_dispatch_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default(),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+        let cs = Blob::compile_from_source(
+            "[numthreads(1, 1, 1)] void CSMain() {}",
+            &[],
+            c"CSMain",
+            c"cs_5_0",
+            0,
+            0,
+        )
+        .unwrap();
+        let pso = device
+            .create_compute_pipeline_state(
+                &ComputePipelineStateDesc::new(&cs).with_root_signature(&root_signature),
+            )
+            .unwrap();
+
+        let arguments = [IndirectArgumentDesc::dispatch()];
+        let command_signature = device
+            .create_command_signature(
+                &CommandSignatureDesc::default()
+                    .with_byte_stride(12)
+                    .with_indirect_arguments(&arguments),
+                None::<&RootSignature>,
+            )
+            .unwrap();
+
+        let (argument_buffer, mut mapped) = unsafe { device.create_upload_buffer(24) }.unwrap();
+        mapped
+            .as_mut_slice()
+            .copy_from_slice(&[1u32, 1, 1, 2, 2, 2].map(u32::to_ne_bytes).concat());
+        let (count_buffer, mut mapped_count) = unsafe { device.create_upload_buffer(4) }.unwrap();
+        mapped_count
+            .as_mut_slice()
+            .copy_from_slice(&1u32.to_ne_bytes());
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        cmd_list.set_compute_root_signature(Some(&root_signature));
+        cmd_list.set_pipeline_state(&pso);
+        cmd_list.execute_indirect(&command_signature, 2, &argument_buffer, 0, RES_NONE, 0);
+        cmd_list.execute_indirect(
+            &command_signature,
+            2,
+            &argument_buffer,
+            0,
+            Some(&count_buffer),
+            0,
+        );
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+    }
+
+    #[test]
+    fn copy_whole_subresource_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let rtv_heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(1))
+            .unwrap();
+        let src = RenderTarget::new(
+            &device,
+            4,
+            4,
+            Format::Rgba8Unorm,
+            rtv_heap.get_cpu_descriptor_handle_for_heap_start(),
+            None,
+        )
+        .unwrap();
+
+        let dst_desc = ResourceDesc::texture_2d(4, 4)
+            .with_format(Format::Rgba8Unorm)
+            .with_array_size(2);
+        let dst = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &dst_desc,
+                ResourceStates::CopyDest,
+                None,
+            )
+            .unwrap();
+
+        let mut layouts = [unsafe { std::mem::zeroed() }; 1];
+        let mut num_rows = [0; 1];
+        let size = device.get_copyable_footprints(
+            &dst_desc,
+            1..2,
+            0,
+            Some(&mut layouts),
+            Some(&mut num_rows),
+            None,
+        );
+        let layout: PlacedSubresourceFootprint = layouts[0];
+
+        let readback = device
+            .create_committed_resource(
+                &HeapProperties::readback(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(size),
+                ResourceStates::CopyDest,
+                None,
+            )
+            .unwrap();
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        cmd_list.clear_render_target_view(src.rtv_handle(), [1.0, 0.0, 0.0, 1.0], &[]);
+        cmd_list.resource_barrier(&[ResourceBarrier::transition(
+            src.resource(),
+            ResourceStates::RenderTarget,
+            ResourceStates::CopySource,
+            None,
+        )]);
+        cmd_list.copy_whole_subresource(&dst, 1, src.resource(), 0);
+        cmd_list.resource_barrier(&[ResourceBarrier::transition(
+            &dst,
+            ResourceStates::CopyDest,
+            ResourceStates::CopySource,
+            None,
+        )]);
+        cmd_list.copy_texture_region(
+            &TextureCopyLocation::placed_footprint(&readback, layout),
+            0,
+            0,
+            0,
+            &TextureCopyLocation::subresource(&dst, 1),
+            None,
+        );
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        let data = readback.map::<u8>(0, Some(0..size)).unwrap();
+        let data = unsafe { std::slice::from_raw_parts(data.as_ptr(), size) };
+
+        for row in 0..num_rows[0] as usize {
+            let start = layout.offset() + row * layout.footprint().row_pitch();
+            for texel in data[start..start + 16].chunks(4) {
+                assert_eq!(texel, [255, 0, 0, 255]);
+            }
+        }
+
+        readback.unmap(0, Some(0..0));
+    }
+
+    #[test]
+    fn scoped_event_test() {
+        let Some(_debug) = debug_layer() else {
+            return;
+        };
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let Ok(info_queue) = device.as_raw().cast::<ID3D12InfoQueue>() else {
+            return;
+        };
+
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        {
+            let _frame = cmd_list.scoped_event(0xff0000ffu32, c"frame");
+            let _draw = cmd_list.scoped_event(0xff00ff00u32, c"draw");
+            cmd_list.set_marker(0xffff0000u32, c"triangle");
+        }
+
+        unsafe { info_queue.ClearStoredMessages() };
+        assert!(cmd_list.close().is_ok());
+        assert_eq!(unsafe { info_queue.GetNumStoredMessages() }, 0);
+    }
+
+    #[test]
+    fn clear_vertex_buffers_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        let buffer = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(256),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+        let address = buffer.get_gpu_virtual_address();
+
+        cmd_list.ia_set_vertex_buffers(
+            0,
+            &[
+                VertexBufferView::new(address, 16, 128),
+                VertexBufferView::new(address + 128, 16, 128),
+            ],
+        );
+        cmd_list.clear_vertex_buffers(0, 2);
+
+        assert!(cmd_list.close().is_ok());
+    }
+
+    #[test]
+    fn root_signature_cache_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default(),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+
+        let mut cache = RootSignatureCache::new(cmd_list);
+        assert!(cache.set_graphics(&root_signature));
+        assert!(!cache.set_graphics(&root_signature));
+        assert!(cache.set_compute(&root_signature));
+        assert!(!cache.set_compute(&root_signature));
+
+        cache.cmd_list().close().unwrap();
+        allocator.reset().unwrap();
+        cache.reset(&allocator, PSO_NONE).unwrap();
+
+        assert!(cache.set_graphics(&root_signature));
+        assert!(cache.into_inner().close().is_ok());
+    }
+
+    #[test]
+    fn root_constant_buffer_view_at_test() {
+        const SHADERS: &str = r#"
+            cbuffer Constants : register(b0) { float4 color; };
+
+            float4 VSMain(uint id : SV_VertexID) : SV_POSITION {
+                return float4(id == 1 ? 3 : -1, id == 2 ? -3 : 1, 0, 1);
+            }
+            float4 PSMain() : SV_TARGET { return color; }
+        "#;
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let parameters = [RootParameter::cbv(0, 0)];
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default().with_parameters(&parameters),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+
+        let vs = Blob::compile_from_source(SHADERS, &[], c"VSMain", c"vs_5_0", 0, 0).unwrap();
+        let ps = Blob::compile_from_source(SHADERS, &[], c"PSMain", c"ps_5_0", 0, 0).unwrap();
+        let pso = device
+            .create_graphics_pipeline(
+                &GraphicsPipelineDesc::new(&vs)
+                    .with_ps(&ps)
+                    .with_root_signature(&root_signature)
+                    .with_primitive_topology(PipelinePrimitiveTopology::Triangle)
+                    .with_render_targets([Format::Rgba8Unorm]),
+            )
+            .unwrap();
+
+        let constants = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(512),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+        constants
+            .write_constant_buffer(0, 256, &[0.0f32, 1.0, 0.0, 1.0])
+            .unwrap();
+
+        let rtv_heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(1))
+            .unwrap();
+        let target = RenderTarget::new(
+            &device,
+            64,
+            64,
+            Format::Rgba8Unorm,
+            rtv_heap.get_cpu_descriptor_handle_for_heap_start(),
+            None,
+        )
+        .unwrap();
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, Some(&pso))
+            .unwrap();
+
+        cmd_list.set_graphics_root_signature(Some(&root_signature));
+        cmd_list.set_graphics_root_constant_buffer_view_at(0, &constants, 256);
+        cmd_list.rs_set_viewports(&[Viewport::from_size((64.0, 64.0))]);
+        cmd_list.rs_set_scissor_rects(&[Rect::default().with_size((64, 64))]);
+        cmd_list.om_set_render_targets(&[target.rtv_handle()], false, None);
+        cmd_list.ia_set_primitive_topology(PrimitiveTopology::Triangle);
+        cmd_list.draw_instanced(3, 1, 0, 0);
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        assert!(device.get_device_removed_reason().is_ok());
+    }
+
+    #[test]
+    fn graphics_root_arguments_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let range = DescriptorRange::srv(1, 0);
+        let parameters = [
+            RootParameter::constant_32bit(0, 0, 4),
+            RootParameter::descriptor_table_single(&range),
+            RootParameter::cbv(1, 0),
+        ];
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default().with_parameters(&parameters),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+
+        let heap = device
+            .create_descriptor_heap(
+                &DescriptorHeapDesc::cbr_srv_uav(1).with_flags(DescriptorHeapFlags::ShaderVisible),
+            )
+            .unwrap();
+        let constants = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(256),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        cmd_list.set_descriptor_heaps(&[Some(heap.clone())]);
+        cmd_list.set_graphics_root_signature(Some(&root_signature));
+        cmd_list.set_graphics_root_32bit_constants(0, &[1.0f32, 0.0, 0.0], 0);
+        cmd_list.set_graphics_root_32bit_constant(0, 1u32, 3);
+        cmd_list
+            .set_graphics_root_descriptor_table(1, heap.get_gpu_descriptor_handle_for_heap_start());
+        cmd_list.set_graphics_root_constant_buffer_view(2, constants.get_gpu_virtual_address());
+
+        assert!(cmd_list.close().is_ok());
+    }
+
+    #[test]
+    fn compute_root_arguments_test() {
+        const SHADER: &str = r#"
+            cbuffer Constants : register(b0) { uint base; };
+            cbuffer RootConstants : register(b1) { uint scale; };
+            RWStructuredBuffer<uint> output : register(u0);
+
+            [numthreads(4, 1, 1)]
+            void CSMain(uint id : SV_DispatchThreadID) { output[id] = base + id * scale; }
+        "#;
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let parameters = [
+            RootParameter::cbv(0, 0),
+            RootParameter::constant_32bit(1, 0, 1),
+            RootParameter::uav(0, 0),
+        ];
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default().with_parameters(&parameters),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+        let cs = Blob::compile_from_source(SHADER, &[], c"CSMain", c"cs_5_0", 0, 0).unwrap();
+        let pso = device
+            .create_compute_pipeline_state(
+                &ComputePipelineStateDesc::new(&cs).with_root_signature(&root_signature),
+            )
+            .unwrap();
+
+        let constants = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(512),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+        constants.write_constant_buffer(0, 256, &7u32).unwrap();
+
+        let output = StructuredBuffer::<u32>::new(&device, 4).unwrap();
+        let readback = device
+            .create_committed_resource(
+                &HeapProperties::readback(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(output.byte_size()),
+                ResourceStates::CopyDest,
+                None,
+            )
+            .unwrap();
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, Some(&pso))
+            .unwrap();
+
+        cmd_list.resource_barrier(&[ResourceBarrier::transition(
+            output.resource(),
+            ResourceStates::Common,
+            ResourceStates::UnorderedAccess,
+            None,
+        )]);
+        cmd_list.set_compute_root_signature(Some(&root_signature));
+        cmd_list.set_compute_root_constant_buffer_view_at(0, &constants, 256);
+        cmd_list.set_compute_root_32bit_constant(1, 2u32, 0);
+        cmd_list.set_compute_root_unordered_access_view(2, output.element_gpu_va(0));
+        cmd_list.dispatch(1, 1, 1);
+        cmd_list.resource_barrier(&[ResourceBarrier::transition(
+            output.resource(),
+            ResourceStates::UnorderedAccess,
+            ResourceStates::CopySource,
+            None,
+        )]);
+        cmd_list.copy_buffer_region(&readback, 0, output.resource(), 0, output.byte_size());
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        let mapped = unsafe { readback.map_data(0, None) }.unwrap();
+        let expected: Vec<u8> = [7u32, 9, 11, 13]
+            .iter()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+        assert_eq!(&mapped[..], &expected[..]);
+    }
+
+    #[test]
+    fn front_and_back_stencil_ref_test() {
+        const SHADERS: &str = r#"
+            float4 VSMain(uint id : SV_VertexID) : SV_POSITION {
+                return float4(id == 1 ? 3 : -1, id == 2 ? -3 : 1, 0, 1);
+            }
+            float4 PSMain() : SV_TARGET { return float4(1, 1, 1, 1); }
+        "#;
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut options = features::Options14Feature::default();
+        if device.check_feature_support(&mut options).is_err()
+            || !options.independent_front_and_back_stencil_ref_mask_supported()
+        {
+            return;
+        }
+
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default(),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+        let vs = Blob::compile_from_source(SHADERS, &[], c"VSMain", c"vs_5_0", 0, 0).unwrap();
+        let ps = Blob::compile_from_source(SHADERS, &[], c"PSMain", c"ps_5_0", 0, 0).unwrap();
+        let pso = device
+            .create_graphics_pipeline(
+                &GraphicsPipelineDesc::new(&vs)
+                    .with_ps(&ps)
+                    .with_root_signature(&root_signature)
+                    .with_primitive_topology(PipelinePrimitiveTopology::Triangle)
+                    .with_render_targets([Format::Rgba8Unorm]),
+            )
+            .unwrap();
+
+        let rtv_heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(1))
+            .unwrap();
+        let target = RenderTarget::new(
+            &device,
+            64,
+            64,
+            Format::Rgba8Unorm,
+            rtv_heap.get_cpu_descriptor_handle_for_heap_start(),
+            None,
+        )
+        .unwrap();
+
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, Some(&pso))
+            .unwrap();
+
+        cmd_list.set_graphics_root_signature(Some(&root_signature));
+        cmd_list.rs_set_viewports(&[Viewport::from_size((64.0, 64.0))]);
+        cmd_list.rs_set_scissor_rects(&[Rect::default().with_size((64, 64))]);
+        cmd_list.om_set_render_targets(&[target.rtv_handle()], false, None);
+        cmd_list.om_set_front_and_back_stencil_ref(&options, 1, 2);
+        cmd_list.ia_set_primitive_topology(PrimitiveTopology::Triangle);
+        cmd_list.draw_instanced(3, 1, 0, 0);
+
+        assert!(cmd_list.close().is_ok());
+    }
+
+    #[test]
+    fn dispatch_rays_test() {
+        use windows::{
+            core::{s, w, Interface, GUID, HRESULT},
+            Win32::{
+                Graphics::Direct3D::Dxc::{
+                    CLSID_DxcCompiler, DxcBuffer, IDxcCompiler3, IDxcIncludeHandler,
+                    IDxcOperationResult, DXC_CP_UTF8,
+                },
+                System::LibraryLoader::{GetProcAddress, LoadLibraryA},
+            },
+        };
+
+        type DxcCreateInstance = unsafe extern "system" fn(
+            *const GUID,
+            *const GUID,
+            *mut *mut std::ffi::c_void,
+        ) -> HRESULT;
+
+        const SHADERS: &str = r#"[shader("raygeneration")] void RayGen() {}"#;
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut options = features::Options5Feature::default();
+        if device.check_feature_support(&mut options).is_err()
+            || options.raytracing_tier() == RaytracingTier::NotSupported
+        {
+            return;
+        }
+
+        // DXR libraries are compiled with DXC, and only a signed container is accepted by every runtime.
+        let library = unsafe {
+            let (Ok(module), Ok(_)) = (
+                LoadLibraryA(s!("dxcompiler.dll")),
+                LoadLibraryA(s!("dxil.dll")),
+            ) else {
+                return;
+            };
+            let Some(create_instance) = GetProcAddress(module, s!("DxcCreateInstance")) else {
+                return;
+            };
+            let create_instance: DxcCreateInstance = std::mem::transmute(create_instance);
+
+            let mut compiler = std::ptr::null_mut();
+            create_instance(&CLSID_DxcCompiler, &IDxcCompiler3::IID, &mut compiler)
+                .ok()
+                .unwrap();
+            let compiler = IDxcCompiler3::from_raw(compiler);
+
+            let source = DxcBuffer {
+                Ptr: SHADERS.as_ptr() as *const _,
+                Size: SHADERS.len(),
+                Encoding: DXC_CP_UTF8.0,
+            };
+            let result: IDxcOperationResult = compiler
+                .Compile(
+                    &source,
+                    Some(&[w!("-T"), w!("lib_6_3")]),
+                    None::<&IDxcIncludeHandler>,
+                )
+                .unwrap();
+            result.GetStatus().unwrap().ok().unwrap();
+
+            let blob = result.GetResult().unwrap();
+            std::slice::from_raw_parts(blob.GetBufferPointer() as *const u8, blob.GetBufferSize())
+                .to_vec()
+        };
+
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default(),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+
+        let library_desc = DxilLibraryDesc::new(library.as_slice());
+        let shader_config = RaytracingShaderConfig::new(4, 8);
+        let pipeline_config = RaytracingPipelineConfig::new(1);
+        let global_root_signature = GlobalRootSignature::new(&root_signature);
+        let subobjects = [
+            StateSubobject::dxil_library(&library_desc),
+            StateSubobject::raytracing_shader_config(&shader_config),
+            StateSubobject::raytracing_pipeline_config(&pipeline_config),
+            StateSubobject::global_root_signature(&global_root_signature),
+        ];
+        let state_object = device
+            .create_state_object(&StateObjectDesc::raytracing_pipeline(&subobjects))
+            .unwrap();
+
+        let identifier = state_object.get_shader_identifier("RayGen").unwrap();
+        assert!(state_object.get_shader_identifier("Missing").is_none());
+
+        let shader_table = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(identifier.len()),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+        shader_table.write_slice(0, 0, &identifier).unwrap();
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList4 = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        cmd_list.set_compute_root_signature(Some(&root_signature));
+        cmd_list.set_pipeline_state1(&state_object);
+        cmd_list.dispatch_rays(&DispatchRaysDesc::new(
+            shader_table.get_gpu_virtual_address(),
+            identifier.len() as u64,
+            1,
+            1,
+            1,
+        ));
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(GraphicsCommandList::from(cmd_list))]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        assert!(device.get_device_removed_reason().is_ok());
+    }
+}
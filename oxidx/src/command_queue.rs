@@ -56,6 +56,10 @@ pub trait ICommandQueue: for<'a> HasInterface<Raw: Interface, RawRef<'a>: Param<
     fn get_desc(&self) -> CommandQueueDesc;
 
     /// This method is used to determine the rate at which the GPU timestamp counter increments.
+    ///
+    /// The result is in ticks per second, dividing a difference of timestamp queries by it gives seconds.
+    ///
+    /// For more information: [`ID3D12CommandQueue::GetTimestampFrequency method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12commandqueue-gettimestampfrequency)
     fn get_timestamp_frequency(&self) -> Result<u64, DxError>;
 
     /// Inserts a user-defined marker into timeline.
@@ -68,13 +72,16 @@ pub trait ICommandQueue: for<'a> HasInterface<Raw: Interface, RawRef<'a>: Param<
 
     /// Updates mappings of tile locations in reserved resources to memory locations in a resource heap.
     ///
+    /// Without a `heap`, every range must be flagged [`TileRangeFlags::Null`] or [`TileRangeFlags::Skip`];
+    /// null ranges unmap the tiles they cover.
+    ///
     /// For more information: [`ID3D12CommandQueue::UpdateTileMappings method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12commandqueue-updatetilemappings)
     fn update_tile_mappings(
         &self,
         resource: &impl IResource,
         resource_region_start_coordinates: Option<&[TiledResourceCoordinate]>,
         resource_region_sizes: Option<&[TileRegionSize]>,
-        heap: &impl IHeap,
+        heap: Option<&impl IHeap>,
         range_flags: Option<&[TileRangeFlags]>,
         heap_range_start_offsets: Option<&[u32]>,
         range_tile_counts: Option<&[u32]>,
@@ -82,6 +89,8 @@ pub trait ICommandQueue: for<'a> HasInterface<Raw: Interface, RawRef<'a>: Param<
 
     /// Queues a GPU-side wait, and returns immediately. A GPU-side wait is where the GPU waits until the specified fence reaches or exceeds the specified value.
     ///
+    /// The fence may be signaled by another queue, which is how work on e.g. a compute queue is ordered after work on a direct queue.
+    ///
     /// For more information: [`ID3D12CommandQueue::Wait method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12commandqueue-wait)
     fn wait(&self, fence: &impl IFence, value: u64) -> Result<(), DxError>;
 }
@@ -185,7 +194,7 @@ impl_trait! {
         resource: &impl IResource,
         resource_region_start_coordinates: Option<&[TiledResourceCoordinate]>,
         resource_region_sizes: Option<&[TileRegionSize]>,
-        heap: &impl IHeap,
+        heap: Option<&impl IHeap>,
         range_flags: Option<&[TileRangeFlags]>,
         heap_range_start_offsets: Option<&[u32]>,
         range_tile_counts: Option<&[u32]>,
@@ -206,12 +215,15 @@ impl_trait! {
             let heap_range_start_offsets = heap_range_start_offsets.map(|r| r.as_ptr());
             let range_tile_counts = range_tile_counts.map(|r| r.as_ptr());
 
+            // Raw heap types share the ID3D12Heap layout, the same way barriers borrow resources.
+            let heap = heap.map(|h| std::mem::transmute_copy::<_, &ID3D12Heap>(&h.as_raw()));
+
             self.0.UpdateTileMappings(
                 resource.as_raw_ref(),
                 regions_size as u32,
                 resource_region_start_coordinates,
                 resource_region_sizes,
-                heap.as_raw_ref(),
+                heap,
                 range_size as u32,
                 range_flags,
                 heap_range_start_offsets,
@@ -229,3 +241,118 @@ impl_trait! {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn get_device_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let queue: CommandQueue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+
+        let owner: Device = queue.get_device().unwrap();
+        let fence = owner.create_fence(0, FenceFlags::empty()).unwrap();
+
+        assert_eq!(fence.get_completed_value(), 0);
+    }
+
+    #[test]
+    fn cross_queue_wait_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let direct = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let compute = device
+            .create_command_queue(&CommandQueueDesc::compute())
+            .unwrap();
+
+        let graphics_done = device.create_fence(0, FenceFlags::empty()).unwrap();
+        let compute_done = device.create_fence(0, FenceFlags::empty()).unwrap();
+
+        compute.wait(&graphics_done, 1).unwrap();
+        compute.signal(&compute_done, 1).unwrap();
+        direct.signal(&graphics_done, 1).unwrap();
+
+        compute_done.wait(1).unwrap();
+
+        assert_eq!(graphics_done.get_completed_value(), 1);
+        assert_eq!(compute_done.get_completed_value(), 1);
+    }
+
+    #[test]
+    fn timestamp_frequency_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+
+        let frequency = queue.get_timestamp_frequency().unwrap();
+        assert!(frequency > 0);
+
+        let (gpu_before, _) = queue.get_clock_calibration().unwrap();
+        let (gpu_after, _) = queue.get_clock_calibration().unwrap();
+        assert!(gpu_after >= gpu_before);
+    }
+
+    #[test]
+    fn update_tile_mappings_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut options = features::OptionsFeature::default();
+        device.check_feature_support(&mut options).unwrap();
+
+        if options.tiled_resources_tier() == TiledResourcesTier::NotSupported {
+            return;
+        }
+
+        let tile_size = TILED_RESOURCE_TILE_SIZE_IN_BYTES as usize;
+
+        let resource = device
+            .create_reserved_resource(
+                &ResourceDesc::buffer(4 * tile_size),
+                ResourceStates::Common,
+                None,
+            )
+            .unwrap();
+        let heap = device
+            .create_heap(
+                &HeapDesc::new(2 * tile_size, HeapProperties::default())
+                    .with_flags(HeapFlags::AllowOnlyBuffers),
+            )
+            .unwrap();
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+
+        let coordinates = [TiledResourceCoordinate::new(0, 0, 0, 0)];
+        let sizes = [TileRegionSize::default().with_tiles(2)];
+
+        queue.update_tile_mappings(
+            &resource,
+            Some(&coordinates),
+            Some(&sizes),
+            Some(&heap),
+            Some(&[TileRangeFlags::empty()]),
+            Some(&[0]),
+            Some(&[2]),
+        );
+        queue.update_tile_mappings(
+            &resource,
+            Some(&coordinates),
+            Some(&sizes),
+            HEAP_NONE,
+            Some(&[TileRangeFlags::Null]),
+            None,
+            Some(&[2]),
+        );
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        assert!(device.get_device_removed_reason().is_ok());
+    }
+}
@@ -19,6 +19,7 @@ conv_enum!(CrossNodeSharingTier to D3D12_CROSS_NODE_SHARING_TIER);
 conv_enum!(CullMode to D3D12_CULL_MODE);
 conv_enum!(DescriptorHeapType to D3D12_DESCRIPTOR_HEAP_TYPE);
 conv_enum!(DescriptorRangeType to D3D12_DESCRIPTOR_RANGE_TYPE);
+conv_enum!(DsvDimension to D3D12_DSV_DIMENSION);
 conv_enum!(FeatureLevel to D3D_FEATURE_LEVEL);
 conv_enum!(FeatureType to D3D12_FEATURE);
 conv_enum!(FillMode to D3D12_FILL_MODE);
@@ -52,16 +53,20 @@ conv_enum!(ResourceHeapTier to D3D12_RESOURCE_HEAP_TIER);
 conv_enum!(ResourceReturnType to D3D_RESOURCE_RETURN_TYPE);
 conv_enum!(RootSignatureVersion to D3D_ROOT_SIGNATURE_VERSION);
 conv_enum!(RotationMode to DXGI_MODE_ROTATION);
+conv_enum!(RtvDimension to D3D12_RTV_DIMENSION);
 conv_enum!(SamplerFeedbackTier to D3D12_SAMPLER_FEEDBACK_TIER);
 conv_enum!(Scaling to DXGI_SCALING);
 conv_enum!(ScalingMode to DXGI_MODE_SCALING);
 conv_enum!(ScanlineOrdering to DXGI_MODE_SCANLINE_ORDER);
+conv_enum!(ShaderComponentMapping to D3D12_SHADER_COMPONENT_MAPPING);
 conv_enum!(ShaderInputType to D3D_SHADER_INPUT_TYPE);
 conv_enum!(ShaderModel to D3D_SHADER_MODEL);
 conv_enum!(ShaderVariableClass to D3D_SHADER_VARIABLE_CLASS);
 conv_enum!(ShaderVariableType to D3D_SHADER_VARIABLE_TYPE);
 conv_enum!(ShaderVarName to D3D_NAME);
 conv_enum!(ShaderVisibility to D3D12_SHADER_VISIBILITY);
+conv_enum!(ShadingRate to D3D12_SHADING_RATE);
+conv_enum!(ShadingRateCombiner to D3D12_SHADING_RATE_COMBINER);
 conv_enum!(SharedResourceCompatibilityTier to D3D12_SHARED_RESOURCE_COMPATIBILITY_TIER);
 conv_enum!(StencilOp to D3D12_STENCIL_OP);
 conv_enum!(SrvDimension to D3D_SRV_DIMENSION);
@@ -74,6 +79,7 @@ conv_enum!(TiledResourcesTier to D3D12_TILED_RESOURCES_TIER);
 conv_enum!(VariableShadingRateTier to D3D12_VARIABLE_SHADING_RATE_TIER);
 conv_enum!(ViewInstancingTier to D3D12_VIEW_INSTANCING_TIER);
 conv_enum!(WaveMmaTier to D3D12_WAVE_MMA_TIER);
+conv_enum!(WriteBufferImmediateMode to D3D12_WRITEBUFFERIMMEDIATE_MODE);
 
 impl CommandQueuePriority {
     #[inline]
@@ -105,10 +111,13 @@ impl HeapAlignment {
 impl From<u64> for HeapAlignment {
     #[inline]
     fn from(value: u64) -> Self {
-        match value as u32 {
+        const RESOURCE_PLACEMENT: u64 = D3D12_DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT as u64;
+        const MSAA_RESOURCE_PLACEMENT: u64 = D3D12_DEFAULT_MSAA_RESOURCE_PLACEMENT_ALIGNMENT as u64;
+
+        match value {
             0 => HeapAlignment::Default,
-            D3D12_DEFAULT_RESOURCE_PLACEMENT_ALIGNMENT => HeapAlignment::ResourcePlacement,
-            D3D12_DEFAULT_MSAA_RESOURCE_PLACEMENT_ALIGNMENT => HeapAlignment::MsaaResourcePlacement,
+            RESOURCE_PLACEMENT => HeapAlignment::ResourcePlacement,
+            MSAA_RESOURCE_PLACEMENT => HeapAlignment::MsaaResourcePlacement,
             _ => unreachable!(),
         }
     }
@@ -22,8 +22,16 @@ pub trait IDescriptorHeap: HasInterface<Raw: Interface> {
 
     /// Gets the GPU descriptor handle that represents the start of the heap.
     ///
+    /// The handle is null unless the heap was created with [`DescriptorHeapFlags::ShaderVisible`](crate::types::DescriptorHeapFlags::ShaderVisible).
+    ///
     /// For more information: [`ID3D12DescriptorHeap::GetGPUDescriptorHandleForHeapStart method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12descriptorheap-getgpudescriptorhandleforheapstart)
     fn get_gpu_descriptor_handle_for_heap_start(&self) -> GpuDescriptorHandle;
+
+    /// Maps a GPU descriptor handle of the shader-visible heap to the CPU descriptor handle of the same slot.
+    ///
+    /// `increment` is the descriptor handle increment size of the heap type.
+    /// Returns [`None`] if `gpu` doesn't point at a descriptor slot of the heap.
+    fn gpu_to_cpu(&self, gpu: GpuDescriptorHandle, increment: u32) -> Option<CpuDescriptorHandle>;
 }
 
 create_type! {
@@ -54,4 +62,91 @@ impl_trait! {
             GpuDescriptorHandle(self.0.GetGPUDescriptorHandleForHeapStart())
         }
     }
+
+    fn gpu_to_cpu(&self, gpu: GpuDescriptorHandle, increment: u32) -> Option<CpuDescriptorHandle> {
+        let gpu_start = self.get_gpu_descriptor_handle_for_heap_start();
+        let cpu_start = self.get_cpu_descriptor_handle_for_heap_start();
+
+        let offset = gpu.0.ptr.checked_sub(gpu_start.0.ptr)?;
+        let increment = increment as u64;
+
+        if increment == 0 || !offset.is_multiple_of(increment) {
+            return None;
+        }
+
+        let index = offset / increment;
+        if index >= self.get_desc().num_descriptors() as u64 {
+            return None;
+        }
+
+        Some(cpu_start.advance(index as usize, increment as usize))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn gpu_to_cpu_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let heap = device
+            .create_descriptor_heap(
+                &DescriptorHeapDesc::cbr_srv_uav(8).with_flags(DescriptorHeapFlags::ShaderVisible),
+            )
+            .unwrap();
+        let increment = device.get_descriptor_handle_increment_size(DescriptorHeapType::CbvSrvUav);
+
+        let gpu = heap
+            .get_gpu_descriptor_handle_for_heap_start()
+            .advance(3, increment);
+
+        assert_eq!(
+            heap.gpu_to_cpu(gpu, increment as u32),
+            Some(
+                heap.get_cpu_descriptor_handle_for_heap_start()
+                    .advance(3, increment)
+            )
+        );
+
+        let start = heap.get_gpu_descriptor_handle_for_heap_start();
+        assert_eq!(
+            heap.gpu_to_cpu(start.advance(8, increment), increment as u32),
+            None
+        );
+        assert_eq!(heap.gpu_to_cpu(start.offset(1), increment as u32), None);
+        assert_eq!(
+            heap.gpu_to_cpu(GpuDescriptorHandle(Default::default()), increment as u32),
+            None
+        );
+    }
+
+    #[test]
+    fn heap_start_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let increment = device.get_descriptor_handle_increment_size(DescriptorHeapType::Rtv);
+        let heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(4))
+            .unwrap();
+
+        let start = heap.get_cpu_descriptor_handle_for_heap_start();
+        assert_ne!(start, CpuDescriptorHandle::default());
+        assert_eq!(start.advance(2, increment), start.offset(2 * increment));
+        assert_eq!(heap.get_gpu_descriptor_handle_for_heap_start().0.ptr, 0);
+    }
+
+    #[test]
+    fn empty_heap_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        assert!(matches!(
+            device.create_descriptor_heap(&DescriptorHeapDesc::rtv(0)),
+            Err(DxError::InvalidArgs)
+        ));
+        assert!(device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(1))
+            .is_ok());
+    }
 }
@@ -3,23 +3,28 @@ use std::{
     ops::Range,
 };
 
+use smallvec::SmallVec;
 use windows::{
-    core::{Interface, PCWSTR},
-    Win32::Graphics::Direct3D12::{ID3D12Device, ID3D12InfoQueue1},
+    core::{Interface, HSTRING, PCWSTR},
+    Win32::Graphics::Direct3D12::{
+        ID3D12CommandAllocator, ID3D12Device, ID3D12Device11, ID3D12Device12, ID3D12Device5,
+        ID3D12InfoQueue1, D3D12_RESOURCE_ALLOCATION_INFO1,
+    },
 };
 
 use crate::{
-    command_allocator::ICommandAllocator,
+    command_allocator::{ICommandAllocator, COMMAND_LIST_TYPE_GUID},
     create_type,
     device_child::IDeviceChild,
     dx::{
         CommandAllocator, CommandQueue, CommandSignature, DescriptorHeap, GraphicsCommandList,
-        Heap, IBlob, IRootSignatureExt, InfoQueue1, PipelineState, QueryHeap, Resource,
-        RootSignature,
+        Heap, IBlob, IRootSignatureExt, InfoQueue1, MappedData, PipelineState, QueryHeap, Resource,
+        RootSignature, StateObject,
     },
     error::DxError,
     heap::IHeap,
     impl_trait,
+    info_queue::validated,
     pageable::Pageable,
     pso::IPipelineState,
     resources::IResource,
@@ -45,11 +50,21 @@ use crate::{
 pub trait IDevice: HasInterface<Raw: Interface> {
     /// Gets information about the features that are supported by the current graphics driver.
     ///
+    /// `feature` carries both the input of the query, e.g. the node index or the requested feature levels
+    /// given to its constructor, and the output, which is readable through its getters once the call succeeded.
+    ///
     /// For more information: [`ID3D12Device::CheckFeatureSupport method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-checkfeaturesupport)
     fn check_feature_support<F: FeatureObject>(&self, feature: &mut F) -> Result<(), DxError>;
 
     /// Copies descriptors from a source to a destination.
     ///
+    /// Range sizes are parallel to the range starts; `None` means every range holds a single descriptor.
+    /// The total number of descriptors on both sides must be equal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a sizes slice is shorter or longer than its starts, or if the totals differ.
+    ///
     /// For more information: [`ID3D12Device::CopyDescriptors method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-copydescriptors)
     fn copy_descriptors<'a>(
         &self,
@@ -62,6 +77,9 @@ pub trait IDevice: HasInterface<Raw: Interface> {
 
     /// Copies descriptors from a source to a destination.
     ///
+    /// The usual way to assemble a descriptor table is to stage descriptors in a heap which is not shader-visible
+    /// and copy them into the shader-visible one, since shader-visible heaps are slow to read from the CPU.
+    ///
     /// For more information: [`ID3D12Device::CopyDescriptorsSimple method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-copydescriptorssimple)
     fn copy_descriptors_simple(
         &self,
@@ -81,6 +99,8 @@ pub trait IDevice: HasInterface<Raw: Interface> {
 
     /// Creates a command list.
     ///
+    /// Returns [`DxError::InvalidArgs`] if `command_allocator` was created for a different [`CommandListType`].
+    ///
     /// For more information: [`ID3D12Device::CreateCommandList method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-createcommandlist)
     fn create_command_list(
         &self,
@@ -106,6 +126,15 @@ pub trait IDevice: HasInterface<Raw: Interface> {
 
     /// Creates both a resource and an implicit heap, such that the heap is big enough to contain the entire resource, and the resource is mapped to the heap.
     ///
+    /// Returns [`DxError::InvalidArgsMsg`] naming the field or flags if `desc` has zero width or height, has more mips than
+    /// [`ResourceDesc::max_mip_levels`], combines [`ResourceFlags::AllowDepthStencil`] with [`ResourceFlags::AllowUnorderedAccess`]
+    /// or [`ResourceFlags::AllowRenderTarget`], or sets [`ResourceFlags::AllowSimultaneousAccess`] on a depth-stencil or multisampled texture.
+    ///
+    /// `optimized_clear_value` must be `None` for buffers and for textures that are neither render targets nor depth-stencil buffers.
+    ///
+    /// The resource follows the legacy [`ResourceStates`] model and must only be transitioned with [`ResourceBarrier`]s,
+    /// even on devices where [`IDevice::supports_enhanced_barriers`] is `true`.
+    ///
     /// For more information: [`ID3D12Device::CreateCommittedResource method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-createcommittedresource)
     fn create_committed_resource(
         &self,
@@ -135,6 +164,8 @@ pub trait IDevice: HasInterface<Raw: Interface> {
 
     /// Creates a depth-stencil view for accessing resource data.
     ///
+    /// With `desc` set to `None` the view covers the first mip level of the whole resource, using the format it was created with.
+    ///
     /// For more information: [`ID3D12Device::CreateDepthStencilView method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-createdepthstencilview)
     fn create_depth_stencil_view(
         &self,
@@ -145,6 +176,8 @@ pub trait IDevice: HasInterface<Raw: Interface> {
 
     /// Creates a descriptor heap object.
     ///
+    /// Returns [`DxError::InvalidArgs`] if `desc` has no descriptors.
+    ///
     /// For more information: [`ID3D12Device::CreateDescriptorHeap method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-createdescriptorheap)
     fn create_descriptor_heap(&self, desc: &DescriptorHeapDesc) -> Result<DescriptorHeap, DxError>;
 
@@ -224,8 +257,18 @@ pub trait IDevice: HasInterface<Raw: Interface> {
     /// For more information: [`ID3D12Device::CreateRootSignature method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-createrootsignature)
     fn create_sampler(&self, desc: &SamplerDesc, dest_descriptor: CpuDescriptorHandle);
 
+    /// Create a sampler object with sampler flags and an optional unsigned integer border color.
+    ///
+    /// Falls back to [`IDevice::create_sampler`] when the device doesn't support `ID3D12Device11`.
+    /// In that case the sampler flags are ignored and an unsigned integer border color is converted to float.
+    ///
+    /// For more information: [`ID3D12Device11::CreateSampler2 method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device11-createsampler2)
+    fn create_sampler2(&self, desc: &SamplerDesc2, dest_descriptor: CpuDescriptorHandle);
+
     /// Creates a shader-resource view for accessing data in a resource.
     ///
+    /// Passing `None` as `resource` writes a null descriptor, `desc` is then required to know the view dimension.
+    ///
     /// For more information: [`ID3D12Device::CreateShaderResourceView method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-createshaderresourceview)
     fn create_shader_resource_view(
         &self,
@@ -234,6 +277,17 @@ pub trait IDevice: HasInterface<Raw: Interface> {
         handle: CpuDescriptorHandle,
     );
 
+    /// Creates a fence with [`FenceFlags::Shared`] and a shared handle to it, which another device or process
+    /// can open with [`IDevice::open_shared_handle`] to signal or wait on the same fence.
+    ///
+    /// Add [`FenceFlags::SharedCrossAdapter`] to share it with a device on a different adapter.
+    fn create_shared_fence(
+        &self,
+        initial_value: u64,
+        flags: FenceFlags,
+        name: Option<&CStr>,
+    ) -> Result<(Fence, SharedHandle), DxError>;
+
     /// Creates a shared handle to a heap, resource, or fence object.
     ///
     /// For more information: [`ID3D12Device::CreateSharedHandle method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-createsharedhandle)
@@ -243,6 +297,21 @@ pub trait IDevice: HasInterface<Raw: Interface> {
         name: Option<&CStr>,
     ) -> Result<SharedHandle, DxError>;
 
+    /// Creates a heap with [`HeapFlags::Shared`] and a shared handle to it, which can be opened
+    /// with [`IDevice::open_shared_handle`] on another device.
+    fn create_shared_heap(
+        &self,
+        desc: &HeapDesc,
+        name: Option<&CStr>,
+    ) -> Result<(Heap, SharedHandle), DxError>;
+
+    /// Creates a state object, such as a raytracing pipeline.
+    ///
+    /// Returns [`DxError::Cast`] when the device doesn't support `ID3D12Device5`.
+    ///
+    /// For more information: [`ID3D12Device5::CreateStateObject method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device5-createstateobject)
+    fn create_state_object(&self, desc: &StateObjectDesc<'_>) -> Result<StateObject, DxError>;
+
     /// Creates a shader-resource view for accessing data in a resource.
     ///
     /// For more information: [`ID3D12Device::CreateUnorderedAccessView method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-createunorderedaccessview)
@@ -254,6 +323,15 @@ pub trait IDevice: HasInterface<Raw: Interface> {
         handle: CpuDescriptorHandle,
     );
 
+    /// Creates a buffer of `size` bytes on an upload heap in the [`ResourceStates::GenericRead`] state
+    /// and maps it. The buffer stays mapped until the returned [`MappedData`] is dropped.
+    ///
+    /// # Safety
+    ///
+    /// The same contract as [`Resource::map_data`]: the returned resource must not be mapped or written through
+    /// [`IResource::map`], [`Resource::map_data`] or [`IResource::write_slice`] while the guard is alive.
+    unsafe fn create_upload_buffer(&self, size: u64) -> Result<(Resource, MappedData), DxError>;
+
     /// Enables the page-out of data, which precludes GPU access of that data.
     ///
     /// For more information: [`ID3D12Device::Evict method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-evict)
@@ -294,6 +372,11 @@ pub trait IDevice: HasInterface<Raw: Interface> {
     /// For more information: [`ID3D12Device::GetDeviceRemovedReason method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-getdeviceremovedreason)
     fn get_device_removed_reason(&self) -> Result<(), DxError>;
 
+    /// Number of planes of `format`, e.g. 2 for depth-stencil and NV12 formats, which multiplies the subresource count of textures.
+    ///
+    /// Returns 0 for formats the driver doesn't know.
+    fn get_format_plane_count(&self, format: Format) -> u8;
+
     /// Reports the number of physical adapters (nodes) that are associated with this device.
     ///
     /// For more information: [`ID3D12Device::GetNodeCount method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-getnodecount)
@@ -308,6 +391,27 @@ pub trait IDevice: HasInterface<Raw: Interface> {
         resource_desc: &[ResourceDesc],
     ) -> ResourceAllocationInfo;
 
+    /// Gets the size and alignment of memory required for a collection of resources, together with the placement of each of them.
+    ///
+    /// `castable_formats` is either empty or parallels `resource_desc`, listing the formats each resource may be viewed as.
+    /// Falls back to [`IDevice::get_resource_allocation_info`] for every resource when the device doesn't support `ID3D12Device12`,
+    /// in which case the castable formats are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `castable_formats` is neither empty nor as long as `resource_desc`.
+    ///
+    /// For more information: [`ID3D12Device12::GetResourceAllocationInfo3 method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device12-getresourceallocationinfo3)
+    fn get_resource_allocation_info3(
+        &self,
+        visible_mask: u32,
+        resource_desc: &[ResourceDesc1],
+        castable_formats: &[&[Format]],
+    ) -> (
+        ResourceAllocationInfo,
+        SmallVec<[ResourceAllocationInfo1; 16]>,
+    );
+
     /// Gets info about how a tiled resource is broken into tiles.
     ///
     /// For more information: [`ID3D12Device::GetResourceTiling method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-getresourcetiling)
@@ -329,17 +433,30 @@ pub trait IDevice: HasInterface<Raw: Interface> {
     /// Opens a handle for shared resources, shared heaps, and shared fences, by using [`SharedHandle`].
     ///
     /// For more information: [`ID3D12Device::OpenSharedHandle method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-opensharedhandle)
-    fn open_shared_handle<D: IDeviceChild>(&self, handle: SharedHandle) -> Result<D, DxError>;
+    fn open_shared_handle<D: IDeviceChild>(&self, handle: &SharedHandle) -> Result<D, DxError>;
 
     /// Opens a handle for shared resources, shared heaps, and shared fences, by using Name.
     ///
     /// For more information: [`ID3D12Device::OpenSharedHandleByName method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-opensharedhandlebyname)
     fn open_shared_handle_by_name(&self, name: &CStr) -> Result<SharedHandle, DxError>;
 
+    /// Size of the upload buffer needed to copy `subresources` of `resource` with [`IGraphicsCommandList::copy_texture_region`](crate::command_list::IGraphicsCommandList::copy_texture_region),
+    /// including the row pitch and placement alignment of each footprint.
+    fn required_intermediate_size(
+        &self,
+        resource: &impl IResource,
+        subresources: Range<u32>,
+    ) -> u64;
+
     /// A development-time aid for certain types of profiling and experimental prototyping.
     ///
     /// For more information: [`ID3D12Device::SetStablePowerState method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12device-setstablepowerstate)
     fn set_stable_power_state(&self, enable: bool) -> Result<(), DxError>;
+
+    /// Whether the driver supports enhanced barriers, as reported by [`Options12Feature`](crate::types::features::Options12Feature).
+    ///
+    /// Returns `false` when the runtime does not know the query.
+    fn supports_enhanced_barriers(&self) -> bool;
 }
 
 create_type! {
@@ -383,6 +500,28 @@ impl_trait! {
         src_descriptor_range_sizes: Option<&'a [u32]>,
         descriptor_heaps_type: DescriptorHeapType,
     ) {
+        assert!(
+            dest_descriptor_range_sizes
+                .is_none_or(|sizes| sizes.len() == dest_descriptor_range_starts.len()),
+            "every destination range needs a size"
+        );
+        assert!(
+            src_descriptor_range_sizes
+                .is_none_or(|sizes| sizes.len() == src_descriptor_range_starts.len()),
+            "every source range needs a size"
+        );
+
+        let total = |starts: &[CpuDescriptorHandle], sizes: Option<&[u32]>| {
+            sizes.map_or(starts.len() as u64, |sizes| {
+                sizes.iter().map(|&size| size as u64).sum()
+            })
+        };
+        assert_eq!(
+            total(dest_descriptor_range_starts, dest_descriptor_range_sizes),
+            total(src_descriptor_range_starts, src_descriptor_range_sizes),
+            "source and destination ranges must hold the same number of descriptors"
+        );
+
         unsafe {
             let dest_num = dest_descriptor_range_starts.len() as u32;
             let dest_descriptor_range_starts = dest_descriptor_range_starts.as_ptr() as *const _;
@@ -426,7 +565,14 @@ impl_trait! {
         r#type: CommandListType
     ) -> Result<CommandAllocator, DxError> {
         unsafe {
-            let res = self.0.CreateCommandAllocator(r#type.as_raw()).map_err(DxError::from)?;
+            let res: ID3D12CommandAllocator = validated(&self.0, || self.0.CreateCommandAllocator(r#type.as_raw()))?;
+
+            let raw_type = r#type.as_raw();
+            validated(&self.0, || res.SetPrivateData(
+                &COMMAND_LIST_TYPE_GUID,
+                std::mem::size_of_val(&raw_type) as u32,
+                Some(&raw_type as *const _ as *const _)
+            ))?;
 
             Ok(CommandAllocator::new(res))
         }
@@ -437,7 +583,7 @@ impl_trait! {
         desc: &CommandQueueDesc,
     ) -> Result<CommandQueue, DxError> {
         unsafe {
-            let res = self.0.CreateCommandQueue(&desc.0).map_err(DxError::from)?;
+            let res = validated(&self.0, || self.0.CreateCommandQueue(&desc.0))?;
 
             Ok(CommandQueue::new(res))
         }
@@ -452,17 +598,17 @@ impl_trait! {
             let mut res = None;
 
             if let Some(root_signature) = root_signature {
-                self.0.CreateCommandSignature(
+                validated(&self.0, || self.0.CreateCommandSignature(
                     &desc.0,
                     root_signature.as_raw_ref(),
                     &mut res
-                ).map_err(DxError::from)?;
+                ))?;
             } else {
-                self.0.CreateCommandSignature(
+                validated(&self.0, || self.0.CreateCommandSignature(
                     &desc.0,
                     None,
                     &mut res
-                ).map_err(DxError::from)?;
+                ))?;
             }
 
             let res = res.unwrap_unchecked();
@@ -479,19 +625,21 @@ impl_trait! {
         initial_state: ResourceStates,
         optimized_clear_value: Option<&ClearValue>,
     ) -> Result<Resource, DxError> {
+        desc.validate()?;
+
         unsafe {
             let clear_value = optimized_clear_value.as_ref().map(|c| &c.0 as *const _);
 
             let mut resource = None;
 
-            self.0.CreateCommittedResource(
+            validated(&self.0, || self.0.CreateCommittedResource(
                 &heap_properties.0,
                 heap_flags.as_raw(),
                 &desc.0,
                 initial_state.as_raw(),
                 clear_value,
                 &mut resource,
-            ).map_err(DxError::from)?;
+            ))?;
 
             let resource = resource.unwrap_unchecked();
 
@@ -506,21 +654,28 @@ impl_trait! {
         command_allocator: &impl ICommandAllocator,
         pso: Option<&impl IPipelineState>,
     ) -> Result<GraphicsCommandList, DxError> {
+        if command_allocator
+            .command_list_type()
+            .is_some_and(|allocator_type| allocator_type != r#type)
+        {
+            return Err(DxError::InvalidArgs);
+        }
+
         unsafe {
             let res = if let Some(pso) = pso {
-                self.0.CreateCommandList(
+                validated(&self.0, || self.0.CreateCommandList(
                     node_mask,
                     r#type.as_raw(),
                     command_allocator.as_raw_ref(),
                     pso.as_raw_ref()
-                ).map_err(DxError::from)?
+                ))?
             } else {
-                self.0.CreateCommandList(
+                validated(&self.0, || self.0.CreateCommandList(
                     node_mask,
                     r#type.as_raw(),
                     command_allocator.as_raw_ref(),
                     None
-                ).map_err(DxError::from)?
+                ))?
             };
 
             Ok(GraphicsCommandList::new(res))
@@ -532,7 +687,7 @@ impl_trait! {
         desc: &ComputePipelineStateDesc<'_>,
     ) -> Result<PipelineState, DxError> {
         unsafe {
-            let res = self.0.CreateComputePipelineState(&desc.0).map_err(DxError::from)?;
+            let res = validated(&self.0, || self.0.CreateComputePipelineState(&desc.0))?;
 
             Ok(PipelineState::new(res))
         }
@@ -583,8 +738,12 @@ impl_trait! {
         &self,
         desc: &DescriptorHeapDesc,
     ) -> Result<DescriptorHeap, DxError> {
+        if desc.0.NumDescriptors == 0 {
+            return Err(DxError::InvalidArgs);
+        }
+
         unsafe {
-            let res = self.0.CreateDescriptorHeap(&desc.0).map_err(DxError::from)?;
+            let res = validated(&self.0, || self.0.CreateDescriptorHeap(&desc.0))?;
 
             Ok(DescriptorHeap::new(res))
         }
@@ -596,7 +755,7 @@ impl_trait! {
         flags: FenceFlags,
     ) -> Result<Fence, DxError> {
         unsafe {
-            let res = self.0.CreateFence(initial_value, flags.as_raw()).map_err(DxError::from)?;
+            let res = validated(&self.0, || self.0.CreateFence(initial_value, flags.as_raw()))?;
 
             Ok(Fence::new(res))
         }
@@ -607,7 +766,7 @@ impl_trait! {
         desc: &GraphicsPipelineDesc<'_>,
     ) -> Result<PipelineState, DxError> {
         unsafe {
-            let res = self.0.CreateGraphicsPipelineState(&desc.0).map_err(DxError::from)?;
+            let res = validated(&self.0, || self.0.CreateGraphicsPipelineState(&desc.0))?;
 
             Ok(PipelineState::new(res))
         }
@@ -616,7 +775,7 @@ impl_trait! {
     fn create_heap(&self, desc: &HeapDesc) -> Result<Heap, DxError> {
         unsafe {
             let mut res = None;
-            self.0.CreateHeap(&desc.0, &mut res).map_err(DxError::from)?;
+            validated(&self.0, || self.0.CreateHeap(&desc.0, &mut res))?;
             let res = res.unwrap_unchecked();
 
             Ok(Heap::new(res))
@@ -642,19 +801,21 @@ impl_trait! {
         initial_state: ResourceStates,
         optimized_clear_value: Option<&ClearValue>,
     ) -> Result<Resource, DxError> {
+        desc.validate()?;
+
         unsafe {
             let clear_value = optimized_clear_value.as_ref().map(|c| &c.0 as *const _);
 
             let mut resource = None;
 
-            self.0.CreatePlacedResource(
+            validated(&self.0, || self.0.CreatePlacedResource(
                 heap.as_raw_ref(),
                 heap_offset as u64,
                 &desc.0,
                 initial_state.as_raw(),
                 clear_value,
                 &mut resource,
-            ).map_err(DxError::from)?;
+            ))?;
 
             let resource = resource.unwrap_unchecked();
 
@@ -668,7 +829,7 @@ impl_trait! {
     ) -> Result<QueryHeap, DxError> {
         unsafe {
             let mut res = None;
-            self.0.CreateQueryHeap(&desc.0, &mut res).map_err(DxError::from)?;
+            validated(&self.0, || self.0.CreateQueryHeap(&desc.0, &mut res))?;
             let res = res.unwrap_unchecked();
             Ok(QueryHeap::new(res))
         }
@@ -705,17 +866,19 @@ impl_trait! {
         initial_state: ResourceStates,
         optimized_clear_value: Option<&ClearValue>,
     ) -> Result<Resource, DxError> {
+        desc.validate()?;
+
         unsafe {
             let clear_value = optimized_clear_value.as_ref().map(|c| &c.0 as *const _);
 
             let mut resource = None;
 
-            self.0.CreateReservedResource(
+            validated(&self.0, || self.0.CreateReservedResource(
                 &desc.0,
                 initial_state.as_raw(),
                 clear_value,
                 &mut resource,
-            ).map_err(DxError::from)?;
+            ))?;
 
             let resource = resource.unwrap_unchecked();
 
@@ -729,7 +892,7 @@ impl_trait! {
         blob: &[u8],
     ) -> Result<RootSignature, DxError> {
         unsafe {
-            let res = self.0.CreateRootSignature(node_mask, blob).map_err(DxError::from)?;
+            let res = validated(&self.0, || self.0.CreateRootSignature(node_mask, blob))?;
 
             Ok(RootSignature::new(res))
         }
@@ -760,6 +923,17 @@ impl_trait! {
         }
     }
 
+    fn create_sampler2(&self, desc: &SamplerDesc2, dest_descriptor: CpuDescriptorHandle) {
+        unsafe {
+            if let Ok(device) = self.0.cast::<ID3D12Device11>() {
+                device.CreateSampler2(&desc.0, dest_descriptor.0);
+            } else {
+                let desc: SamplerDesc = (*desc).into();
+                self.0.CreateSampler(&desc.0, dest_descriptor.0);
+            }
+        }
+    }
+
     fn create_shader_resource_view(
         &self,
         resource: Option<&impl IResource>,
@@ -786,28 +960,60 @@ impl_trait! {
         }
     }
 
+    fn create_shared_fence(
+        &self,
+        initial_value: u64,
+        flags: FenceFlags,
+        name: Option<&CStr>,
+    ) -> Result<(Fence, SharedHandle), DxError> {
+        let fence = self.create_fence(initial_value, flags | FenceFlags::Shared)?;
+        let handle = self.create_shared_handle(&fence, name)?;
+
+        Ok((fence, handle))
+    }
+
     fn create_shared_handle(
         &self,
         shareable: &impl IDeviceChild,
         name: Option<&CStr>,
     ) -> Result<SharedHandle, DxError> {
         unsafe {
-            let name = PCWSTR::from_raw(
-                name.map(|name| name.as_ptr())
-                    .unwrap_or(std::ptr::null())
-                    as *const _
-            );
-            let handle = self.0.CreateSharedHandle(
+            let name = name.map(|name| HSTRING::from(&*name.to_string_lossy()));
+            let name = name.as_ref().map_or(PCWSTR::null(), |name| PCWSTR::from_raw(name.as_ptr()));
+            let handle = validated(&self.0, || self.0.CreateSharedHandle(
                 shareable.as_raw_ref(),
                 None,
                 0x10000000,
                 name
-            ).map_err(DxError::from)?;
+            ))?;
 
             Ok(SharedHandle(handle))
         }
     }
 
+    fn create_shared_heap(
+        &self,
+        desc: &HeapDesc,
+        name: Option<&CStr>,
+    ) -> Result<(Heap, SharedHandle), DxError> {
+        let desc = desc.with_flags(desc.flags() | HeapFlags::Shared);
+        let heap = self.create_heap(&desc)?;
+        let handle = self.create_shared_handle(&heap, name)?;
+
+        Ok((heap, handle))
+    }
+
+    fn create_state_object(&self, desc: &StateObjectDesc<'_>) -> Result<StateObject, DxError> {
+        unsafe {
+            let device = self.0.cast::<ID3D12Device5>()
+                .map_err(|_| DxError::Cast("ID3D12Device", "ID3D12Device5"))?;
+
+            let res = validated(&self.0, || device.CreateStateObject(&desc.0))?;
+
+            Ok(StateObject::new(res))
+        }
+    }
+
     fn create_unordered_access_view(
         &self,
         resource: Option<&impl IResource>,
@@ -855,9 +1061,22 @@ impl_trait! {
         }
     }
 
+    unsafe fn create_upload_buffer(&self, size: u64) -> Result<(Resource, MappedData), DxError> {
+        let buffer = self.create_committed_resource(
+            &HeapProperties::upload(),
+            HeapFlags::empty(),
+            &ResourceDesc::buffer(size as usize),
+            ResourceStates::GenericRead,
+            None,
+        )?;
+        let mapped = MappedData::new(buffer.clone(), 0, size as usize, Some(0..0))?;
+
+        Ok((buffer, mapped))
+    }
+
     fn evict(&self, objects: &[Option<Pageable>]) -> Result<(), DxError> {
         unsafe {
-            self.0.Evict(std::mem::transmute::<&_, &_>(objects)).map_err(DxError::from)
+            validated(&self.0, || self.0.Evict(std::mem::transmute::<&_, &_>(objects)))
         }
     }
 
@@ -912,7 +1131,16 @@ impl_trait! {
 
     fn get_device_removed_reason(&self) -> Result<(), DxError> {
         unsafe {
-            self.0.GetDeviceRemovedReason().map_err(DxError::from)
+            validated(&self.0, || self.0.GetDeviceRemovedReason())
+        }
+    }
+
+    fn get_format_plane_count(&self, format: Format) -> u8 {
+        let mut info = features::FormatInfoFeature::new(format);
+
+        match self.check_feature_support(&mut info) {
+            Ok(()) => info.plane_count(),
+            Err(_) => 0,
         }
     }
 
@@ -934,6 +1162,64 @@ impl_trait! {
         }
     }
 
+    fn get_resource_allocation_info3(
+        &self,
+        visible_mask: u32,
+        resource_desc: &[ResourceDesc1],
+        castable_formats: &[&[Format]],
+    ) -> (ResourceAllocationInfo, SmallVec<[ResourceAllocationInfo1; 16]>) {
+        assert!(
+            castable_formats.is_empty() || castable_formats.len() == resource_desc.len(),
+            "castable_formats must be empty or have an entry for every resource"
+        );
+
+        let mut infos: SmallVec<[ResourceAllocationInfo1; 16]> =
+            smallvec::smallvec![Default::default(); resource_desc.len()];
+
+        unsafe {
+            let Ok(device) = self.0.cast::<ID3D12Device12>() else {
+                let mut total = ResourceAllocationInfo::default();
+
+                for (desc, info) in resource_desc.iter().zip(infos.iter_mut()) {
+                    let single = self.get_resource_allocation_info(visible_mask, &[desc.base()]);
+                    let offset = total.size().next_multiple_of(single.alignment().max(1));
+
+                    info.0 = D3D12_RESOURCE_ALLOCATION_INFO1 {
+                        Offset: offset,
+                        Alignment: single.alignment(),
+                        SizeInBytes: single.size(),
+                    };
+                    total.0.SizeInBytes = offset + single.size();
+                    total.0.Alignment = total.alignment().max(single.alignment());
+                }
+
+                return (total, infos);
+            };
+
+            let counts: SmallVec<[u32; 16]> =
+                castable_formats.iter().map(|f| f.len() as u32).collect();
+            let formats: SmallVec<[*const _; 16]> =
+                castable_formats.iter().map(|f| f.as_ptr() as *const _).collect();
+
+            let (counts, formats) = if castable_formats.is_empty() {
+                (None, None)
+            } else {
+                (Some(counts.as_ptr()), Some(formats.as_ptr()))
+            };
+
+            let total = device.GetResourceAllocationInfo3(
+                visible_mask,
+                resource_desc.len() as u32,
+                resource_desc.as_ptr() as *const _,
+                counts,
+                formats,
+                Some(infos.as_mut_ptr() as *mut _),
+            );
+
+            (ResourceAllocationInfo(total), infos)
+        }
+    }
+
     fn get_resource_tiling(
         &self,
         resource: &impl IResource,
@@ -966,13 +1252,13 @@ impl_trait! {
         unsafe {
             let objects = std::slice::from_raw_parts(objects.as_ptr() as *const _, objects.len());
 
-            self.0.MakeResident(objects).map_err(DxError::from)
+            validated(&self.0, || self.0.MakeResident(objects))
         }
     }
 
     fn open_shared_handle<D: IDeviceChild>(
         &self,
-        handle: SharedHandle,
+        handle: &SharedHandle,
     ) -> Result<D, DxError> {
         unsafe {
             let mut res = None;
@@ -985,22 +1271,849 @@ impl_trait! {
 
     fn open_shared_handle_by_name(&self, name: &CStr) -> Result<SharedHandle, DxError> {
         unsafe {
-            let name = PCWSTR::from_raw(
-                name.as_ptr()
-                    as *const _
-            );
-            let handle = self.0.OpenSharedHandleByName(
-                name,
+            let name = HSTRING::from(&*name.to_string_lossy());
+            let handle = validated(&self.0, || self.0.OpenSharedHandleByName(
+                &name,
                 0x10000000,
-            ).map_err(DxError::from)?;
+            ))?;
 
             Ok(SharedHandle(handle))
         }
     }
 
+    fn required_intermediate_size(&self, resource: &impl IResource, subresources: Range<u32>) -> u64 {
+        self.get_copyable_footprints(&resource.get_desc(), subresources, 0, None, None, None) as u64
+    }
+
     fn set_stable_power_state(&self, enable: bool) -> Result<(), DxError> {
         unsafe {
-            self.0.SetStablePowerState(enable).map_err(DxError::from)
+            validated(&self.0, || self.0.SetStablePowerState(enable))
+        }
+    }
+
+    fn supports_enhanced_barriers(&self) -> bool {
+        let mut options = features::Options12Feature::default();
+
+        self.check_feature_support(&mut options).is_ok() && options.enhanced_barriers_supported()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use windows::{
+        core::Interface,
+        Win32::Graphics::Direct3D12::{ID3D12Device11, ID3D12Device12, ID3D12InfoQueue},
+    };
+
+    use crate::{dx::*, HasInterface};
+
+    #[test]
+    fn create_sampler2_uint_border_color_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        if device.as_raw().cast::<ID3D12Device11>().is_err() {
+            return;
+        }
+
+        let heap = device
+            .create_descriptor_heap(
+                &DescriptorHeapDesc::sampler(1).with_flags(DescriptorHeapFlags::ShaderVisible),
+            )
+            .unwrap();
+
+        let desc = SamplerDesc2::point()
+            .with_address_u(AddressMode::Border)
+            .with_address_v(AddressMode::Border)
+            .with_address_w(AddressMode::Border)
+            .with_comparison_func(ComparisonFunc::Never)
+            .with_uint_border_color([1, 2, 3, 4])
+            .with_lod(0.0..f32::MAX);
+
+        assert!(desc.flags().contains(SamplerFlags::UintBorderColor));
+
+        device.create_sampler2(&desc, heap.get_cpu_descriptor_handle_for_heap_start());
+        assert!(device.get_device_removed_reason().is_ok());
+    }
+
+    #[test]
+    fn shared_cross_adapter_heap_test() {
+        let factory = create_factory4(FactoryCreationFlags::empty()).unwrap();
+        let Ok(warp) = factory.enum_warp_adapters() else {
+            return;
+        };
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let warp_device = create_device(Some(&warp), FeatureLevel::Level11).unwrap();
+
+        let mut options = features::OptionsFeature::default();
+        device.check_feature_support(&mut options).unwrap();
+
+        if !options.cross_adapter_row_major_texture_supported() {
+            return;
+        }
+
+        let desc = ResourceDesc::texture_2d(256, 256)
+            .with_format(Format::Rgba8Unorm)
+            .with_layout(TextureLayout::RowMajor)
+            .with_flags(ResourceFlags::AllowCrossAdapter);
+        let info = device.get_resource_allocation_info(0, &[desc]);
+
+        let (heap, handle) = device
+            .create_shared_heap(&HeapDesc::shared_cross_adapter(info.size() as usize), None)
+            .unwrap();
+        let shared_heap: Heap = warp_device.open_shared_handle(&handle).unwrap();
+        handle.close().unwrap();
+
+        assert!(heap
+            .get_desc()
+            .flags()
+            .contains(HeapFlags::SharedCrossAdapter));
+
+        device
+            .create_placed_resource(&heap, 0, &desc, ResourceStates::Common, None)
+            .unwrap();
+        warp_device
+            .create_placed_resource(&shared_heap, 0, &desc, ResourceStates::Common, None)
+            .unwrap();
+    }
+
+    #[test]
+    fn zero_size_resource_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let buffer = device.create_committed_resource(
+            &HeapProperties::upload(),
+            HeapFlags::empty(),
+            &ResourceDesc::buffer(0),
+            ResourceStates::GenericRead,
+            None,
+        );
+        assert!(
+            matches!(buffer, Err(DxError::InvalidArgsMsg(message)) if message.contains("width"))
+        );
+
+        let texture = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::texture_2d(64, 0).with_format(Format::Rgba8Unorm),
+            ResourceStates::Common,
+            None,
+        );
+        assert!(
+            matches!(texture, Err(DxError::InvalidArgsMsg(message)) if message.contains("height"))
+        );
+    }
+
+    #[test]
+    fn depth_stencil_flags_validation_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let create = |flags| {
+            device.create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(64, 64)
+                    .with_format(Format::R32Typeless)
+                    .with_flags(flags),
+                ResourceStates::Common,
+                None,
+            )
+        };
+
+        assert!(matches!(
+            create(ResourceFlags::AllowDepthStencil | ResourceFlags::AllowUnorderedAccess),
+            Err(DxError::InvalidArgsMsg(message)) if message.contains("AllowUnorderedAccess")
+        ));
+        assert!(matches!(
+            create(ResourceFlags::AllowDepthStencil | ResourceFlags::AllowRenderTarget),
+            Err(DxError::InvalidArgsMsg(message)) if message.contains("AllowRenderTarget")
+        ));
+
+        let texture = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::texture_2d(64, 64)
+                .with_format(Format::Rgba8Unorm)
+                .with_flags(ResourceFlags::AllowRenderTarget | ResourceFlags::AllowUnorderedAccess),
+            ResourceStates::Common,
+            None,
+        );
+        assert!(texture.is_ok());
+    }
+
+    #[test]
+    fn committed_resource_on_node_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let node_count = device.get_node_count();
+        if node_count < 2 {
+            return;
+        }
+
+        let heap_properties = HeapProperties::default().on_node(1 << 1, (1 << node_count) - 1);
+        assert_eq!(heap_properties.creation_node_mask(), 0b10);
+
+        let resource = device.create_committed_resource(
+            &heap_properties,
+            HeapFlags::empty(),
+            &ResourceDesc::buffer(256),
+            ResourceStates::Common,
+            None,
+        );
+
+        assert!(resource.is_ok());
+        assert!(device.get_device_removed_reason().is_ok());
+    }
+
+    #[test]
+    fn rtv_desc_from_resource_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let desc = ResourceDesc::texture_2d(64, 64)
+            .with_format(Format::Rgba8Unorm)
+            .with_flags(ResourceFlags::AllowRenderTarget);
+        let texture = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &desc,
+                ResourceStates::RenderTarget,
+                None,
+            )
+            .unwrap();
+
+        let rtv_desc = RenderTargetViewDesc::from_resource(&desc).unwrap();
+        assert_eq!(rtv_desc.dimension(), RtvDimension::Texture2D);
+        assert_eq!(rtv_desc.format(), Format::Rgba8Unorm);
+        assert!(RenderTargetViewDesc::from_resource(&ResourceDesc::buffer(256)).is_none());
+
+        let heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(1))
+            .unwrap();
+        device.create_render_target_view(
+            Some(&texture),
+            Some(&rtv_desc),
+            heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+    }
+
+    #[test]
+    fn create_upload_buffer_test() {
+        const SIZE: usize = 4096;
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let (upload, mut mapped) = unsafe { device.create_upload_buffer(SIZE as u64) }.unwrap();
+        assert_eq!(mapped.len(), SIZE);
+
+        for (i, byte) in mapped.as_mut_slice().iter_mut().enumerate() {
+            *byte = i as u8;
         }
+        drop(mapped);
+
+        let readback = device
+            .create_committed_resource(
+                &HeapProperties::readback(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(SIZE),
+                ResourceStates::CopyDest,
+                None,
+            )
+            .unwrap();
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+        cmd_list.copy_buffer_region(&readback, 0, &upload, 0, SIZE);
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        let data = readback.map::<u8>(0, Some(0..SIZE)).unwrap();
+        let data = unsafe { std::slice::from_raw_parts(data.as_ptr(), SIZE) };
+        assert!(data.iter().enumerate().all(|(i, byte)| *byte == i as u8));
+        readback.unmap(0, Some(0..0));
+    }
+
+    #[test]
+    fn texture_cube_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let desc = ResourceDesc::texture_cube(Format::Rgba8Unorm, 64, 4);
+        assert_eq!(desc.depth_or_array_size(), 6);
+
+        let cubemap = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &desc,
+                ResourceStates::PixelShaderResource,
+                None,
+            )
+            .unwrap();
+        assert_eq!(cubemap.get_desc().depth_or_array_size(), 6);
+        assert_eq!(cubemap.get_desc().mip_levels(), 4);
+
+        let heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::cbr_srv_uav(1))
+            .unwrap();
+        device.create_shader_resource_view(
+            Some(&cubemap),
+            Some(&ShaderResourceViewDesc::texture_cube(
+                Format::Rgba8Unorm,
+                0,
+                u32::MAX,
+                0.0,
+            )),
+            heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+
+        assert!(device.get_device_removed_reason().is_ok());
+    }
+
+    #[test]
+    fn required_intermediate_size_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let texture = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(100, 100)
+                    .with_format(Format::Rgba8Unorm)
+                    .with_mip_levels(7),
+                ResourceStates::CopyDest,
+                None,
+            )
+            .unwrap();
+
+        let pixel_bytes = (0..7)
+            .map(|mip| (100u64 >> mip).max(1).pow(2) * 4)
+            .sum::<u64>();
+
+        let size = device.required_intermediate_size(&texture, 0..7);
+        assert!(size > pixel_bytes);
+        assert!(device.required_intermediate_size(&texture, 0..1) < size);
+    }
+
+    #[test]
+    fn committed_resource_clear_value_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let color = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::texture_2d(64, 64)
+                .with_format(Format::Rgba8Unorm)
+                .with_flags(ResourceFlags::AllowRenderTarget),
+            ResourceStates::RenderTarget,
+            Some(&ClearValue::color(Format::Rgba8Unorm, [0.0, 0.0, 0.0, 1.0])),
+        );
+        assert!(color.is_ok());
+
+        let depth = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::texture_2d(64, 64)
+                .with_format(Format::D24UnormS8Uint)
+                .with_flags(ResourceFlags::AllowDepthStencil),
+            ResourceStates::DepthWrite,
+            Some(&ClearValue::depth(Format::D24UnormS8Uint, 1.0, 0)),
+        );
+        assert!(depth.is_ok());
+
+        let buffer = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::buffer(256),
+            ResourceStates::Common,
+            None,
+        );
+        assert!(buffer.is_ok());
+    }
+
+    #[test]
+    fn resource_desc_constructors_test() {
+        let buffer = ResourceDesc::buffer(1024);
+        assert_eq!(buffer.dimension(), ResourceDimension::Buffer);
+        assert_eq!(buffer.width(), 1024);
+        assert_eq!(buffer.height(), 1);
+        assert_eq!(buffer.depth_or_array_size(), 1);
+        assert_eq!(buffer.mip_levels(), 1);
+        assert_eq!(buffer.format(), Format::Unknown);
+        assert_eq!(buffer.layout(), TextureLayout::RowMajor);
+
+        let depth = ResourceDesc::texture_2d(800, 600)
+            .with_format(Format::D32Float)
+            .with_flags(ResourceFlags::AllowDepthStencil);
+        assert_eq!(depth.dimension(), ResourceDimension::Texture2D);
+        assert_eq!(depth.width(), 800);
+        assert_eq!(depth.height(), 600);
+        assert_eq!(depth.flags(), ResourceFlags::AllowDepthStencil);
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let resource = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &depth,
+                ResourceStates::DepthWrite,
+                Some(&ClearValue::depth(Format::D32Float, 1.0, 0)),
+            )
+            .unwrap();
+        assert_eq!(resource.get_desc().format(), Format::D32Float);
+    }
+
+    #[test]
+    fn create_depth_stencil_view_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let depth = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(128, 128)
+                    .with_format(Format::D24UnormS8Uint)
+                    .with_flags(ResourceFlags::AllowDepthStencil),
+                ResourceStates::DepthWrite,
+                Some(&ClearValue::depth(Format::D24UnormS8Uint, 1.0, 0)),
+            )
+            .unwrap();
+
+        let heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::dsv(2))
+            .unwrap();
+        let size = device.get_descriptor_handle_increment_size(DescriptorHeapType::Dsv);
+        let handle = heap.get_cpu_descriptor_handle_for_heap_start();
+
+        let desc = DepthStencilViewDesc::texture_2d(Format::D24UnormS8Uint, 0);
+        assert_eq!(desc.dimension(), DsvDimension::Texture2D);
+        assert_eq!(desc.flags(), DsvFlags::empty());
+
+        device.create_depth_stencil_view(Some(&depth), None, handle);
+        device.create_depth_stencil_view(Some(&depth), Some(&desc), handle.advance(1, size));
+    }
+
+    #[test]
+    fn create_shader_resource_view_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let texture = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(64, 64).with_format(Format::Rgba8Unorm),
+                ResourceStates::PixelShaderResource,
+                None,
+            )
+            .unwrap();
+
+        let heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::cbr_srv_uav(2))
+            .unwrap();
+        let size = device.get_descriptor_handle_increment_size(DescriptorHeapType::CbvSrvUav);
+        let handle = heap.get_cpu_descriptor_handle_for_heap_start();
+
+        let desc = ShaderResourceViewDesc::texture_2d(Format::Rgba8Unorm, 0, 1, 0.0, 0)
+            .with_component_mapping(
+                ShaderComponentMapping::FromMemoryComponent2,
+                ShaderComponentMapping::FromMemoryComponent1,
+                ShaderComponentMapping::FromMemoryComponent0,
+                ShaderComponentMapping::ForceValue1,
+            );
+        assert_eq!(desc.format(), Format::Rgba8Unorm);
+        assert_eq!(desc.component_mapping(), 0x1000 | 2 | (1 << 3) | (5 << 9));
+
+        device.create_shader_resource_view(Some(&texture), Some(&desc), handle);
+
+        // Null descriptor, reads return zero
+        device.create_shader_resource_view(
+            RES_NONE,
+            Some(&ShaderResourceViewDesc::texture_2d(
+                Format::Rgba8Unorm,
+                0,
+                1,
+                0.0,
+                0,
+            )),
+            handle.advance(1, size),
+        );
+    }
+
+    #[test]
+    fn resource_desc1_test() {
+        let desc = ResourceDesc::texture_2d(256, 128)
+            .with_format(Format::Rgba8Unorm)
+            .with_mip_levels(4)
+            .with_flags(ResourceFlags::AllowUnorderedAccess);
+
+        let desc1 = ResourceDesc1::from(desc);
+        assert_eq!(desc1.base(), desc);
+        assert_eq!(desc1.sampler_feedback_mip_region(), MipRegion::default());
+
+        let desc1 = desc1.with_sampler_feedback_mip_region(MipRegion::new(4, 4, 1));
+        assert_eq!(desc1.base(), desc);
+        assert_eq!(desc1.sampler_feedback_mip_region().width(), 4);
+    }
+
+    #[test]
+    fn resource_allocation_info3_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        if device.as_raw().cast::<ID3D12Device12>().is_err() {
+            return;
+        }
+
+        let descs = [
+            ResourceDesc1::from(ResourceDesc::texture_2d(256, 256).with_format(Format::Rgba8Unorm)),
+            ResourceDesc1::from(ResourceDesc::buffer(1024)),
+        ];
+        let (total, infos) =
+            device.get_resource_allocation_info3(0, &descs, &[&[Format::Rgba8UnormSrgb], &[]]);
+
+        assert_eq!(infos.len(), 2);
+        assert!(infos[1].offset() >= infos[0].offset() + infos[0].size());
+        assert!(total.size() >= infos[1].offset() + infos[1].size());
+    }
+
+    #[test]
+    fn simultaneous_access_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let buffer = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::buffer(1024).with_flags(ResourceFlags::AllowSimultaneousAccess),
+            ResourceStates::Common,
+            None,
+        );
+        assert!(buffer.is_ok());
+
+        let depth = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::texture_2d(64, 64)
+                .with_format(Format::D32Float)
+                .with_flags(
+                    ResourceFlags::AllowDepthStencil | ResourceFlags::AllowSimultaneousAccess,
+                ),
+            ResourceStates::DepthWrite,
+            None,
+        );
+        assert!(matches!(
+            depth,
+            Err(DxError::InvalidArgsMsg(message)) if message.contains("AllowDepthStencil")
+        ));
+
+        let msaa = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::texture_2d(64, 64)
+                .with_format(Format::Rgba8Unorm)
+                .with_sample_desc(SampleDesc::new(4, 0))
+                .with_flags(
+                    ResourceFlags::AllowRenderTarget | ResourceFlags::AllowSimultaneousAccess,
+                ),
+            ResourceStates::RenderTarget,
+            None,
+        );
+        assert!(matches!(
+            msaa,
+            Err(DxError::InvalidArgsMsg(message)) if message.contains("sample count")
+        ));
+    }
+
+    #[test]
+    fn placed_resource_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let heap = device
+            .create_heap(
+                &HeapDesc::new(8 << 20, HeapProperties::default())
+                    .with_alignment(HeapAlignment::MsaaResourcePlacement)
+                    .with_flags(HeapFlags::AllowOnlyRtDsTextures),
+            )
+            .unwrap();
+        assert_eq!(
+            heap.get_desc().alignement(),
+            HeapAlignment::MsaaResourcePlacement
+        );
+
+        let desc = ResourceDesc::texture_2d(256, 256)
+            .with_format(Format::Rgba8Unorm)
+            .with_flags(ResourceFlags::AllowRenderTarget);
+        let info = device.get_resource_allocation_info(0, &[desc]);
+
+        let first =
+            device.create_placed_resource(&heap, 0, &desc, ResourceStates::RenderTarget, None);
+        let second = device.create_placed_resource(
+            &heap,
+            info.size().next_multiple_of(info.alignment()) as usize,
+            &desc,
+            ResourceStates::RenderTarget,
+            None,
+        );
+        assert!(first.is_ok());
+        assert!(second.is_ok());
+    }
+
+    #[test]
+    fn read_only_depth_stencil_view_test() {
+        let Some(_debug) = debug_layer() else {
+            return;
+        };
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let Ok(info_queue) = device.as_raw().cast::<ID3D12InfoQueue>() else {
+            return;
+        };
+
+        let depth = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(128, 128)
+                    .with_format(Format::R24G8Typeless)
+                    .with_flags(ResourceFlags::AllowDepthStencil),
+                ResourceStates::DepthRead | ResourceStates::PixelShaderResource,
+                Some(&ClearValue::depth(Format::D24UnormS8Uint, 1.0, 0)),
+            )
+            .unwrap();
+
+        let dsv_heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::dsv(1))
+            .unwrap();
+        let srv_heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::cbr_srv_uav(1))
+            .unwrap();
+
+        unsafe { info_queue.ClearStoredMessages() };
+
+        let dsv = DepthStencilViewDesc::texture_2d(Format::D24UnormS8Uint, 0)
+            .with_flags(DsvFlags::ReadOnlyDepth | DsvFlags::ReadOnlyStencil);
+        assert_eq!(
+            dsv.flags(),
+            DsvFlags::ReadOnlyDepth | DsvFlags::ReadOnlyStencil
+        );
+
+        device.create_depth_stencil_view(
+            Some(&depth),
+            Some(&dsv),
+            dsv_heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+        device.create_shader_resource_view(
+            Some(&depth),
+            Some(&ShaderResourceViewDesc::texture_2d(
+                Format::R24UnormX8Typeless,
+                0,
+                1,
+                0.0,
+                0,
+            )),
+            srv_heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+
+        assert_eq!(unsafe { info_queue.GetNumStoredMessages() }, 0);
+    }
+
+    #[test]
+    fn format_plane_count_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        assert_eq!(device.get_format_plane_count(Format::D24UnormS8Uint), 2);
+        assert_eq!(device.get_format_plane_count(Format::Rgba8Unorm), 1);
+    }
+
+    #[test]
+    fn shader_resource_view_mip_range_test() {
+        let Some(_debug) = debug_layer() else {
+            return;
+        };
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let Ok(info_queue) = device.as_raw().cast::<ID3D12InfoQueue>() else {
+            return;
+        };
+
+        let texture = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(32, 32)
+                    .with_format(Format::Rgba8Unorm)
+                    .with_mip_levels(6),
+                ResourceStates::PixelShaderResource,
+                None,
+            )
+            .unwrap();
+        let heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::cbr_srv_uav(1))
+            .unwrap();
+
+        let desc = ShaderResourceViewDesc::texture_2d(Format::Rgba8Unorm, 2, u32::MAX, 2.0, 0);
+        assert_eq!(desc.most_detailed_mip(), 2);
+        assert_eq!(desc.mip_levels(), u32::MAX);
+        assert_eq!(desc.resource_min_lod_clamp(), 2.0);
+
+        unsafe { info_queue.ClearStoredMessages() };
+
+        device.create_shader_resource_view(
+            Some(&texture),
+            Some(&desc),
+            heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+
+        assert_eq!(unsafe { info_queue.GetNumStoredMessages() }, 0);
+    }
+
+    #[test]
+    fn supports_enhanced_barriers_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut options = features::Options12Feature::default();
+        let expected = device.check_feature_support(&mut options).is_ok()
+            && options.enhanced_barriers_supported();
+
+        assert_eq!(device.supports_enhanced_barriers(), expected);
+    }
+
+    #[test]
+    fn shared_fence_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+
+        let (fence, handle) = device
+            .create_shared_fence(0, FenceFlags::empty(), Some(c"oxidx_shared_fence_test"))
+            .unwrap();
+        let fence1 = Fence1::try_from(fence.clone()).unwrap();
+        assert!(fence1.get_creation_flags().contains(FenceFlags::Shared));
+
+        let opened: Fence = device.open_shared_handle(&handle).unwrap();
+        handle.close().unwrap();
+
+        let by_name = device
+            .open_shared_handle_by_name(c"oxidx_shared_fence_test")
+            .unwrap();
+        by_name.close().unwrap();
+
+        queue.signal(&fence, 3).unwrap();
+        opened.wait(3).unwrap();
+        assert_eq!(opened.get_completed_value(), 3);
+    }
+
+    #[test]
+    fn max_mip_levels_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let desc = ResourceDesc::texture_2d(256, 256).with_format(Format::Rgba8Unorm);
+        assert_eq!(desc.max_mip_levels(), 9);
+        assert_eq!(ResourceDesc::texture_2d(256, 100).max_mip_levels(), 9);
+        assert_eq!(ResourceDesc::texture_2d(1, 1).max_mip_levels(), 1);
+        assert_eq!(ResourceDesc::buffer(1 << 20).max_mip_levels(), 1);
+
+        let create = |mip_levels| {
+            device.create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &desc.with_mip_levels(mip_levels),
+                ResourceStates::Common,
+                None,
+            )
+        };
+
+        assert!(create(0).is_ok());
+        assert!(create(9).is_ok());
+        assert!(matches!(
+            create(12),
+            Err(DxError::InvalidArgsMsg(message)) if message.contains("mip_levels")
+        ));
+    }
+
+    #[test]
+    fn copy_descriptors_test() {
+        let Some(_debug) = debug_layer() else {
+            return;
+        };
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let Ok(info_queue) = device.as_raw().cast::<ID3D12InfoQueue>() else {
+            return;
+        };
+
+        let increment = device.get_descriptor_handle_increment_size(DescriptorHeapType::CbvSrvUav);
+        let staging = device
+            .create_descriptor_heap(&DescriptorHeapDesc::cbr_srv_uav(4))
+            .unwrap();
+        let visible = device
+            .create_descriptor_heap(
+                &DescriptorHeapDesc::cbr_srv_uav(8).with_flags(DescriptorHeapFlags::ShaderVisible),
+            )
+            .unwrap();
+
+        let src = staging.get_cpu_descriptor_handle_for_heap_start();
+        let dst = visible.get_cpu_descriptor_handle_for_heap_start();
+
+        for i in 0..4 {
+            device.create_shader_resource_view(
+                RES_NONE,
+                Some(&ShaderResourceViewDesc::texture_2d(
+                    Format::Rgba8Unorm,
+                    0,
+                    1,
+                    0.0,
+                    0,
+                )),
+                src.advance(i, increment),
+            );
+        }
+
+        unsafe { info_queue.ClearStoredMessages() };
+
+        device.copy_descriptors_simple(4, dst, src, DescriptorHeapType::CbvSrvUav);
+        device.copy_descriptors(
+            &[dst.advance(4, increment)],
+            Some(&[4]),
+            &[src, src.advance(2, increment)],
+            Some(&[2, 2]),
+            DescriptorHeapType::CbvSrvUav,
+        );
+
+        assert_eq!(unsafe { info_queue.GetNumStoredMessages() }, 0);
+    }
+
+    #[test]
+    fn shared_handle_drop_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let (fence, handle) = device
+            .create_shared_fence(0, FenceFlags::empty(), None)
+            .unwrap();
+
+        let opened: Fence = {
+            let handle = handle;
+            device.open_shared_handle(&handle).unwrap()
+        };
+
+        fence.signal(2).unwrap();
+        assert_eq!(opened.get_completed_value(), 2);
+
+        let raw = device
+            .create_shared_handle(&fence, None)
+            .unwrap()
+            .into_raw();
+        assert!(unsafe { windows::Win32::Foundation::CloseHandle(raw) }.is_ok());
     }
 }
@@ -6,11 +6,15 @@ use windows::{
 };
 
 use crate::{
+    command_queue::CommandQueue,
     create_type,
-    dx::{Device, DxError},
+    descriptor_heap::DescriptorHeap,
+    device::IDevice,
+    error::DxError,
     heap::Heap,
     impl_trait, impl_up_down_cast,
-    resources::Resource,
+    pso::PipelineState,
+    resources::{Buffer, Resource, Texture},
     sync::{Fence, Fence1},
     HasInterface,
 };
@@ -26,7 +30,7 @@ pub trait IDeviceChild:
     /// Gets a pointer to the device that created this interface.
     ///
     /// For more information: [`ID3D12DeviceChild::GetDevice interface`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12devicechild-getdevice)
-    fn get_device(&self) -> Result<Device, DxError>;
+    fn get_device<D: IDevice>(&self) -> Result<D, DxError>;
 }
 
 pub trait IDeviceChildExt: IDeviceChild {
@@ -45,17 +49,22 @@ create_type!(
 impl_trait! {
     impl IDeviceChild =>
     DeviceChild,
+    CommandQueue,
+    DescriptorHeap,
     Heap,
     Resource,
+    Buffer,
+    Texture,
     Fence,
-    Fence1;
+    Fence1,
+    PipelineState;
 
-    fn get_device(&self) -> Result<Device, DxError> {
+    fn get_device<D: IDevice>(&self) -> Result<D, DxError> {
         unsafe {
             let mut raw = None;
-            self.0.GetDevice(&mut raw).map_err(DxError::from)?;
+            self.0.GetDevice::<D::Raw>(&mut raw).map_err(DxError::from)?;
 
-            Ok(Device::new(raw.unwrap()))
+            Ok(D::new(raw.unwrap()))
         }
     }
 }
@@ -63,10 +72,15 @@ impl_trait! {
 impl_trait! {
     impl IDeviceChildExt =>
     DeviceChild,
+    CommandQueue,
+    DescriptorHeap,
     Heap,
     Resource,
+    Buffer,
+    Texture,
     Fence,
-    Fence1;
+    Fence1,
+    PipelineState;
 
     fn set_debug_object_name(&self, name: &CStr) -> Result<(), DxError> {
         unsafe {
@@ -80,3 +94,32 @@ impl_up_down_cast!(Heap inherit DeviceChild);
 impl_up_down_cast!(Resource inherit DeviceChild);
 impl_up_down_cast!(Fence inherit DeviceChild);
 impl_up_down_cast!(Fence1 inherit DeviceChild);
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn get_device_luid_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let resource = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(256),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+        let heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(1))
+            .unwrap();
+
+        let from_resource: Device = resource.get_device().unwrap();
+        let from_heap: Device = heap.get_device().unwrap();
+
+        assert_eq!(from_resource.get_adapter_luid(), device.get_adapter_luid());
+        assert_eq!(from_heap.get_adapter_luid(), device.get_adapter_luid());
+    }
+}
@@ -11,14 +11,18 @@ pub use crate::device_child::*;
 pub use crate::entry::*;
 pub use crate::error::*;
 pub use crate::factory::*;
+pub use crate::gpu_timer::*;
 pub use crate::heap::*;
 pub use crate::info_queue::*;
 pub use crate::pageable::*;
 pub use crate::pso::*;
 pub use crate::query_heap::*;
 pub use crate::reflection::*;
+pub use crate::render_target::*;
 pub use crate::resources::*;
 pub use crate::root_signature::*;
+pub use crate::structured_buffer::*;
 pub use crate::swapchain::*;
 pub use crate::sync::*;
 pub use crate::types::*;
+pub use crate::upload_ring::*;
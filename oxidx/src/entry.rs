@@ -1,7 +1,11 @@
+#[cfg(test)]
+use std::sync::LazyLock;
+
 use windows::Win32::Graphics::Direct3D12::{D3D12CreateDevice, D3D12GetDebugInterface};
 use windows::Win32::Graphics::Dxgi::CreateDXGIFactory2;
 
 use crate::adapter::IAdapter3;
+use crate::debug::IDebug;
 use crate::dx::{Debug, Device, Factory4};
 use crate::error::DxError;
 use crate::types::{FactoryCreationFlags, FeatureLevel};
@@ -20,11 +24,16 @@ pub fn create_factory4(flags: FactoryCreationFlags) -> Result<Factory4, DxError>
 
 /// Creates a device that represents the display adapter.
 ///
+/// To validate the device with the debug layer, call [`enable_debug_layer`] first.
+///
 /// For more information: [`D3D12CreateDevice function`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-d3d12createdevice)
 pub fn create_device(
     adapter: Option<&impl IAdapter3>,
     feature_level: FeatureLevel,
 ) -> Result<Device, DxError> {
+    #[cfg(test)]
+    debug_layer();
+
     unsafe {
         let mut inner = None;
 
@@ -55,6 +64,29 @@ pub fn create_debug() -> Result<Debug, DxError> {
     }
 }
 
+/// Gets the debug interface and enables the debug layer.
+///
+/// Must be called before [`create_device`], enabling the layer removes devices which already exist.
+/// The returned interface should be kept alive for as long as the debug layer is needed.
+///
+/// For more information: [`ID3D12Debug::EnableDebugLayer method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12sdklayers/nf-d3d12sdklayers-id3d12debug-enabledebuglayer)
+pub fn enable_debug_layer() -> Result<Debug, DxError> {
+    let debug = create_debug()?;
+    debug.enable_debug_layer();
+
+    Ok(debug)
+}
+
+/// Debug layer shared by the test binary, enabled once before its first device is created.
+///
+/// Tests must use it instead of [`enable_debug_layer`], which would remove devices of tests running in parallel.
+#[cfg(test)]
+pub(crate) fn debug_layer() -> Option<&'static Debug> {
+    static DEBUG: LazyLock<Option<Debug>> = LazyLock::new(|| enable_debug_layer().ok());
+
+    DEBUG.as_ref()
+}
+
 #[cfg(test)]
 mod test {
     use crate::{dx::ADAPTER_NONE, types::FactoryCreationFlags};
@@ -68,9 +100,24 @@ mod test {
         assert!(factory.is_ok())
     }
 
+    #[test]
+    fn create_factory4_release_test() {
+        let factory = create_factory4(FactoryCreationFlags::empty());
+
+        assert!(factory.is_ok())
+    }
+
     #[test]
     fn create_device_test() {
         let device = create_device(ADAPTER_NONE, FeatureLevel::Level11);
         assert!(device.is_ok());
     }
+
+    #[test]
+    fn enable_debug_layer_test() {
+        assert!(debug_layer().is_some());
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11);
+        assert!(device.is_ok());
+    }
 }
@@ -26,14 +26,27 @@ pub enum DxError {
     #[error("The previous blit operation that is transferring information to or from this surface is incomplete.")]
     WasStillDrawing,
 
-    /// Generic error. Enable debug layer to get detailed error information
-    #[error("Enable debug layer to get detailed error information.")]
+    /// Generic error, displayed with the system message of the failed call
+    #[error("{0}")]
     Fail(String),
 
+    /// An error returned by the device together with the first validation message it reported
+    #[error("{error}: {message}")]
+    Validation {
+        /// The error returned by the failed call
+        error: Box<DxError>,
+        /// The first error or warning stored by the debug layer during the call
+        message: String,
+    },
+
     /// An invalid parameter was passed to the returning function
     #[error("An invalid parameter was passed to the returning function.")]
     InvalidArgs,
 
+    /// An invalid parameter was passed to the returning function, names the offending parameter
+    #[error("An invalid parameter was passed to the returning function: {0}.")]
+    InvalidArgsMsg(&'static str),
+
     /// Direct3D could not allocate sufficient memory to complete the call
     #[error("Direct3D could not allocate sufficient memory to complete the call.")]
     Oom,
@@ -43,6 +56,10 @@ pub enum DxError {
     NotImpl,
 
     // DXGI
+    /// The device was removed, contains the reason reported by the device
+    #[error("The device was removed: {0}")]
+    DeviceRemoved(String),
+
     /// Generic DXGI error
     #[error("{0}")]
     Dxgi(String),
@@ -50,4 +67,9 @@ pub enum DxError {
     /// Shader compilation error
     #[error("{0}")]
     ShaderCompilationError(String),
+
+    // Win32
+    /// The wait ended before the awaited objects were signaled
+    #[error("The wait timed out.")]
+    Timeout,
 }
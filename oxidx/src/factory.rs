@@ -8,6 +8,7 @@ use windows::Win32::Graphics::Dxgi::{
 
 use crate::command_queue::ICommandQueue;
 use crate::swapchain::{IOutput1, Swapchain1};
+use crate::sync::Event;
 use crate::types::*;
 use crate::{adapter::Adapter3, error::DxError};
 use crate::{create_type, impl_trait, HasInterface};
@@ -60,6 +61,26 @@ pub trait IFactory4: HasInterface<Raw: Interface> {
         hwnd: NonZeroIsize,
         flags: WindowAssociationFlags,
     ) -> Result<(), DxError>;
+
+    /// Registers to receive notification of changes in occlusion status by using event signaling.
+    ///
+    /// For more information: [`IDXGIFactory2::RegisterOcclusionStatusEvent method`](https://learn.microsoft.com/en-us/windows/win32/api/dxgi1_2/nf-dxgi1_2-idxgifactory2-registerocclusionstatusevent)
+    fn register_occlusion_status_event(&self, event: &Event) -> Result<u32, DxError>;
+
+    /// Unregisters a window or an event to stop it from receiving notification when occlusion status changes.
+    ///
+    /// For more information: [`IDXGIFactory2::UnregisterOcclusionStatus method`](https://learn.microsoft.com/en-us/windows/win32/api/dxgi1_2/nf-dxgi1_2-idxgifactory2-unregisterocclusionstatus)
+    fn unregister_occlusion_status(&self, cookie: u32);
+
+    /// Registers to receive notification of changes in stereo status by using event signaling.
+    ///
+    /// For more information: [`IDXGIFactory2::RegisterStereoStatusEvent method`](https://learn.microsoft.com/en-us/windows/win32/api/dxgi1_2/nf-dxgi1_2-idxgifactory2-registerstereostatusevent)
+    fn register_stereo_status_event(&self, event: &Event) -> Result<u32, DxError>;
+
+    /// Unregisters a window or an event to stop it from receiving notification when stereo status changes.
+    ///
+    /// For more information: [`IDXGIFactory2::UnregisterStereoStatus method`](https://learn.microsoft.com/en-us/windows/win32/api/dxgi1_2/nf-dxgi1_2-idxgifactory2-unregisterstereostatus)
+    fn unregister_stereo_status(&self, cookie: u32);
 }
 
 pub trait IFactory6 {
@@ -179,6 +200,30 @@ impl_trait! {
             Ok(())
         }
     }
+
+    fn register_occlusion_status_event(&self, event: &Event) -> Result<u32, DxError> {
+        unsafe {
+            self.0.RegisterOcclusionStatusEvent(event.0).map_err(DxError::from)
+        }
+    }
+
+    fn unregister_occlusion_status(&self, cookie: u32) {
+        unsafe {
+            self.0.UnregisterOcclusionStatus(cookie);
+        }
+    }
+
+    fn register_stereo_status_event(&self, event: &Event) -> Result<u32, DxError> {
+        unsafe {
+            self.0.RegisterStereoStatusEvent(event.0).map_err(DxError::from)
+        }
+    }
+
+    fn unregister_stereo_status(&self, cookie: u32) {
+        unsafe {
+            self.0.UnregisterStereoStatus(cookie);
+        }
+    }
 }
 
 impl_trait! {
@@ -194,3 +239,22 @@ impl_trait! {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn occlusion_status_event_test() {
+        let factory = create_factory4(FactoryCreationFlags::empty()).unwrap();
+        let event = Event::create(false, false).unwrap();
+
+        let cookie = factory.register_occlusion_status_event(&event).unwrap();
+        factory.unregister_occlusion_status(cookie);
+
+        let cookie = factory.register_stereo_status_event(&event).unwrap();
+        factory.unregister_stereo_status(cookie);
+
+        event.close().unwrap();
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{
+    command_list::IGraphicsCommandList,
+    command_queue::ICommandQueue,
+    device::IDevice,
+    error::DxError,
+    query_heap::QueryHeap,
+    resources::{IResource, Resource},
+    types::*,
+};
+
+const TIMESTAMP_SIZE: usize = std::mem::size_of::<u64>();
+
+/// Measures named GPU scopes with timestamp queries.
+///
+/// Each frame uses its own half of the query heap and readback buffer. [`GpuTimer::results`] reads the frame
+/// resolved two frames ago, so the previous frame may still be executing on the GPU while it is called.
+/// Scopes may be nested or repeated within a frame.
+#[derive(Debug)]
+pub struct GpuTimer {
+    query_heap: QueryHeap,
+    readback: Resource,
+    frequency: u64,
+    queries_per_frame: usize,
+    frame: usize,
+    next_query: usize,
+    open: Vec<(String, usize)>,
+    scopes: Vec<(String, usize, usize)>,
+    resolved: [Vec<(String, usize, usize)>; 2],
+}
+
+impl GpuTimer {
+    /// Creates a timer able to record up to `max_scopes` scopes per frame on command lists executed by `queue`.
+    pub fn new(
+        device: &impl IDevice,
+        queue: &impl ICommandQueue,
+        max_scopes: usize,
+    ) -> Result<Self, DxError> {
+        let queries_per_frame = max_scopes * 2;
+
+        let query_heap =
+            device.create_query_heap(&QueryHeapDesc::timestamp(queries_per_frame * 2))?;
+        let readback = device.create_committed_resource(
+            &HeapProperties::readback(),
+            HeapFlags::empty(),
+            &ResourceDesc::buffer(queries_per_frame * 2 * TIMESTAMP_SIZE),
+            ResourceStates::CopyDest,
+            None,
+        )?;
+
+        Ok(Self {
+            query_heap,
+            readback,
+            frequency: queue.get_timestamp_frequency()?,
+            queries_per_frame,
+            frame: 0,
+            next_query: 0,
+            open: vec![],
+            scopes: vec![],
+            resolved: Default::default(),
+        })
+    }
+
+    /// Ticks per second of the queue the timer was created for.
+    #[inline]
+    pub fn frequency(&self) -> u64 {
+        self.frequency
+    }
+
+    /// Opens the scope `name`.
+    ///
+    /// Returns [`DxError::Oom`] when the frame has run out of queries.
+    pub fn begin(
+        &mut self,
+        cmd_list: &impl IGraphicsCommandList,
+        name: impl Into<String>,
+    ) -> Result<(), DxError> {
+        let query = self.timestamp(cmd_list)?;
+        self.open.push((name.into(), query));
+
+        Ok(())
+    }
+
+    /// Closes the innermost open scope named `name`.
+    ///
+    /// Returns [`DxError::InvalidArgs`] if there is no such scope.
+    pub fn end(&mut self, cmd_list: &impl IGraphicsCommandList, name: &str) -> Result<(), DxError> {
+        let position = self
+            .open
+            .iter()
+            .rposition(|(n, _)| n == name)
+            .ok_or(DxError::InvalidArgs)?;
+
+        let query = self.timestamp(cmd_list)?;
+        let (name, begin) = self.open.remove(position);
+        self.scopes.push((name, begin, query));
+
+        Ok(())
+    }
+
+    /// Copies the timestamps of the current frame into the readback buffer and starts a new frame.
+    ///
+    /// Scopes which are still open are discarded.
+    pub fn resolve(&mut self, cmd_list: &impl IGraphicsCommandList) {
+        let base = self.frame * self.queries_per_frame;
+
+        if self.next_query > 0 {
+            cmd_list.resolve_query_data(
+                &self.query_heap,
+                QueryType::Timestamp,
+                base..base + self.next_query,
+                &self.readback,
+                base * TIMESTAMP_SIZE,
+            );
+        }
+
+        self.resolved[self.frame] = std::mem::take(&mut self.scopes);
+        self.open.clear();
+        self.frame ^= 1;
+        self.next_query = 0;
+    }
+
+    /// Durations in milliseconds of the scopes from the frame resolved two frames ago, in the order they were closed.
+    ///
+    /// The command list containing the second to last [`GpuTimer::resolve`] call must have finished executing,
+    /// and the results must be read before the command list of the current frame is executed.
+    pub fn results(&self) -> Result<Vec<(String, f64)>, DxError> {
+        let resolved = &self.resolved[self.frame];
+
+        if resolved.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let base = self.frame * self.queries_per_frame;
+        let range = base * TIMESTAMP_SIZE..(base + self.queries_per_frame) * TIMESTAMP_SIZE;
+
+        let timestamps = self.readback.map::<u64>(0, Some(range))?;
+        let timestamps = unsafe {
+            std::slice::from_raw_parts(timestamps.as_ptr().add(base), self.queries_per_frame)
+        };
+
+        let results = resolved
+            .iter()
+            .map(|(name, begin, end)| {
+                let ticks = timestamps[*end].saturating_sub(timestamps[*begin]);
+                (name.clone(), ticks as f64 * 1000.0 / self.frequency as f64)
+            })
+            .collect();
+
+        self.readback.unmap(0, Some(0..0));
+
+        Ok(results)
+    }
+
+    fn timestamp(&mut self, cmd_list: &impl IGraphicsCommandList) -> Result<usize, DxError> {
+        if self.next_query == self.queries_per_frame {
+            return Err(DxError::Oom);
+        }
+
+        let query = self.next_query;
+        self.next_query += 1;
+
+        cmd_list.end_query(
+            &self.query_heap,
+            QueryType::Timestamp,
+            self.frame * self.queries_per_frame + query,
+        );
+
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn gpu_timer_copy_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let allocator = device
+            .create_command_allocator(CommandListType::Direct)
+            .unwrap();
+        let cmd_list: GraphicsCommandList = device
+            .create_command_list(0, CommandListType::Direct, &allocator, PSO_NONE)
+            .unwrap();
+
+        let create_buffer = |state| {
+            device
+                .create_committed_resource(
+                    &HeapProperties::default(),
+                    HeapFlags::empty(),
+                    &ResourceDesc::buffer(1 << 20),
+                    state,
+                    None,
+                )
+                .unwrap()
+        };
+        let src = create_buffer(ResourceStates::CopySource);
+        let dst = create_buffer(ResourceStates::CopyDest);
+
+        let mut timer = GpuTimer::new(&device, &queue, 4).unwrap();
+
+        timer.begin(&cmd_list, "frame").unwrap();
+        timer.begin(&cmd_list, "copy").unwrap();
+        cmd_list.copy_buffer_region(&dst, 0, &src, 0, 1 << 20);
+        timer.end(&cmd_list, "copy").unwrap();
+        timer.end(&cmd_list, "frame").unwrap();
+        assert!(timer.end(&cmd_list, "frame").is_err());
+
+        timer.resolve(&cmd_list);
+        assert!(timer.results().unwrap().is_empty());
+
+        // An empty second frame, so the first one is the frame read by `results`.
+        timer.resolve(&cmd_list);
+        cmd_list.close().unwrap();
+
+        queue.execute_command_lists(&[Some(cmd_list)]);
+
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+
+        let results = timer.results().unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "copy");
+        assert_eq!(results[1].0, "frame");
+        assert!(results.iter().all(|(_, ms)| *ms >= 0.0));
+        assert!(results[1].1 >= results[0].1);
+    }
+}
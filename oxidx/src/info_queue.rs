@@ -6,7 +6,7 @@ use std::{
 };
 
 use windows::{
-    core::{Param, PCSTR},
+    core::{Interface, Param, PCSTR},
     Win32::Graphics::Direct3D12::{
         ID3D12InfoQueue1, D3D12_MESSAGE_CATEGORY, D3D12_MESSAGE_ID, D3D12_MESSAGE_SEVERITY,
     },
@@ -115,3 +115,111 @@ unsafe extern "system" fn dx_callback(
     let callback = pcontext.cast::<CallbackData>();
     (*callback)(category.into(), severity.into(), id.into(), message);
 }
+
+/// Runs a fallible `call` on `device` and converts its error.
+///
+/// With the `autolog-validation` feature the messages stored in the info queue during the call are inspected
+/// and the first error or warning is returned as [`DxError::Validation`] next to the original error.
+/// The stored messages are left in the queue.
+#[cfg(feature = "autolog-validation")]
+pub(crate) fn validated<T>(
+    device: &impl Interface,
+    call: impl FnOnce() -> windows::core::Result<T>,
+) -> Result<T, DxError> {
+    use windows::Win32::Graphics::Direct3D12::ID3D12InfoQueue;
+
+    let queue = device.cast::<ID3D12InfoQueue>().ok();
+    let start = queue
+        .as_ref()
+        .map_or(0, |queue| unsafe { queue.GetNumStoredMessages() });
+
+    call().map_err(|error| {
+        let message = queue
+            .as_ref()
+            .and_then(|queue| unsafe { first_validation_message(queue, start) });
+
+        match message {
+            Some(message) => DxError::Validation {
+                error: Box::new(error.into()),
+                message,
+            },
+            None => error.into(),
+        }
+    })
+}
+
+#[cfg(not(feature = "autolog-validation"))]
+#[inline]
+pub(crate) fn validated<T>(
+    _device: &impl Interface,
+    call: impl FnOnce() -> windows::core::Result<T>,
+) -> Result<T, DxError> {
+    call().map_err(DxError::from)
+}
+
+#[cfg(feature = "autolog-validation")]
+unsafe fn first_validation_message(
+    queue: &windows::Win32::Graphics::Direct3D12::ID3D12InfoQueue,
+    start: u64,
+) -> Option<String> {
+    use windows::Win32::Graphics::Direct3D12::{D3D12_MESSAGE, D3D12_MESSAGE_SEVERITY_WARNING};
+
+    let count = queue.GetNumStoredMessages();
+
+    (start..count).find_map(|index| {
+        let mut size = 0;
+        queue.GetMessage(index, None, &mut size).ok()?;
+
+        let mut storage = vec![0u64; size.div_ceil(std::mem::size_of::<u64>())];
+        let message = storage.as_mut_ptr().cast::<D3D12_MESSAGE>();
+        queue.GetMessage(index, Some(message), &mut size).ok()?;
+
+        let message = &*message;
+
+        if message.Severity.0 > D3D12_MESSAGE_SEVERITY_WARNING.0 {
+            return None;
+        }
+
+        let description = std::slice::from_raw_parts(
+            message.pDescription,
+            message.DescriptionByteLength.saturating_sub(1),
+        );
+
+        Some(String::from_utf8_lossy(description).into_owned())
+    })
+}
+
+#[cfg(all(test, feature = "autolog-validation"))]
+mod test {
+    use windows::{core::Interface, Win32::Graphics::Direct3D12::ID3D12InfoQueue};
+
+    use crate::{dx::*, HasInterface};
+
+    #[test]
+    fn autolog_validation_test() {
+        let Some(_debug) = debug_layer() else {
+            return;
+        };
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let result = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::texture_2d(64, 64).with_format(Format::Unknown),
+            ResourceStates::Common,
+            None,
+        );
+
+        match result {
+            Err(DxError::Validation { error, message }) => {
+                assert!(matches!(*error, DxError::InvalidArgs | DxError::Fail(_)));
+                assert!(message.contains("CreateCommittedResource"));
+            }
+            other => panic!("expected a validation message, got {other:?}"),
+        }
+
+        let queue = device.as_raw().cast::<ID3D12InfoQueue>().unwrap();
+        assert!(unsafe { queue.GetNumStoredMessages() } > 0);
+    }
+}
@@ -18,17 +18,21 @@ pub mod entry;
 pub mod error;
 pub mod ext;
 pub mod factory;
+pub mod gpu_timer;
 pub mod heap;
 pub mod info_queue;
 pub mod pageable;
 pub mod pso;
 pub mod query_heap;
 pub mod reflection;
+pub mod render_target;
 pub mod resources;
 pub mod root_signature;
+pub mod structured_buffer;
 pub mod swapchain;
 pub mod sync;
 pub mod types;
+pub mod upload_ring;
 
 pub(crate) mod pix;
 
@@ -1,5 +1,5 @@
 use windows::{
-    core::{Interface, Param},
+    core::{Interface, Param, HSTRING},
     Win32::Graphics::Direct3D12::*,
 };
 
@@ -36,3 +36,168 @@ impl_trait! {
         }
     }
 }
+
+/// Represents a variable amount of configuration state, including shaders, that an application manages as a single unit
+/// and which is given to a driver atomically to process, such as a raytracing pipeline.
+///
+/// For more information: [`ID3D12StateObject interface`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nn-d3d12-id3d12stateobject)
+pub trait IStateObject:
+    for<'a> HasInterface<Raw: Interface, RawRef<'a>: Param<ID3D12StateObject>>
+{
+    /// Gets the identifier of the shader or hit group exported as `export_name`, to be written into a shader record.
+    ///
+    /// Returns [`None`] if there is no such export.
+    ///
+    /// For more information: [`ID3D12StateObjectProperties::GetShaderIdentifier method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12stateobjectproperties-getshaderidentifier)
+    fn get_shader_identifier(
+        &self,
+        export_name: &str,
+    ) -> Option<[u8; D3D12_SHADER_IDENTIFIER_SIZE_IN_BYTES as usize]>;
+}
+
+create_type! {
+    /// Represents a variable amount of configuration state, including shaders, that an application manages as a single unit
+    /// and which is given to a driver atomically to process, such as a raytracing pipeline.
+    ///
+    /// For more information: [`ID3D12StateObject interface`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nn-d3d12-id3d12stateobject)
+    StateObject wrap ID3D12StateObject
+}
+
+impl_trait! {
+    impl IStateObject =>
+    StateObject;
+
+    fn get_shader_identifier(
+        &self,
+        export_name: &str,
+    ) -> Option<[u8; D3D12_SHADER_IDENTIFIER_SIZE_IN_BYTES as usize]> {
+        unsafe {
+            let properties = self.0.cast::<ID3D12StateObjectProperties>().ok()?;
+            let identifier = properties.GetShaderIdentifier(&HSTRING::from(export_name));
+
+            if identifier.is_null() {
+                return None;
+            }
+
+            Some(*(identifier as *const [u8; D3D12_SHADER_IDENTIFIER_SIZE_IN_BYTES as usize]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn pso_from_bytecode_slice_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default(),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+
+        let blob = Blob::compile_from_source(
+            "[numthreads(1, 1, 1)] void CSMain() {}",
+            &[],
+            c"CSMain",
+            c"cs_5_0",
+            0,
+            0,
+        )
+        .unwrap();
+
+        let bytecode = unsafe {
+            std::slice::from_raw_parts(blob.get_buffer_ptr::<u8>().as_ptr(), blob.get_buffer_size())
+        }
+        .to_vec();
+
+        let desc = ComputePipelineStateDesc::new(ShaderBytecode::Slice(&bytecode))
+            .with_root_signature(&root_signature);
+
+        assert!(device.create_compute_pipeline_state(&desc).is_ok());
+    }
+
+    #[test]
+    fn compute_pso_cache_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default(),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+
+        let cs = Blob::compile_from_source(
+            "[numthreads(8, 8, 1)] void CSMain() {}",
+            &[],
+            c"CSMain",
+            c"cs_5_0",
+            0,
+            0,
+        )
+        .unwrap();
+
+        let pso = device
+            .create_compute_pipeline_state(
+                &ComputePipelineStateDesc::new(&cs)
+                    .with_root_signature(&root_signature)
+                    .with_node_mask(0),
+            )
+            .unwrap();
+        let cache = pso.get_cached_blob().unwrap();
+
+        let desc = ComputePipelineStateDesc::new(&cs)
+            .with_root_signature(&root_signature)
+            .with_cache(&cache);
+
+        assert!(device.create_compute_pipeline_state(&desc).is_ok());
+    }
+
+    #[test]
+    fn blend_desc_presets_test() {
+        const SHADERS: &str = r#"
+            float4 VSMain(uint id : SV_VertexID) : SV_POSITION { return float4(0, 0, 0, 1); }
+            float4 PSMain() : SV_TARGET { return float4(1, 1, 1, 0.5); }
+        "#;
+
+        let disabled = RenderTargetBlendDesc::disabled();
+        assert!(!disabled.0.BlendEnable.as_bool());
+        assert_eq!(
+            disabled.0.RenderTargetWriteMask,
+            ColorWriteEnable::all().bits() as u8
+        );
+        assert_eq!(BlendDesc::opaque().0.RenderTarget, [disabled.0; 8]);
+        assert!(BlendDesc::alpha_blend().0.RenderTarget[0]
+            .BlendEnable
+            .as_bool());
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let root_signature = device
+            .serialize_and_create_root_signature(
+                &RootSignatureDesc::default(),
+                RootSignatureVersion::V1_0,
+                0,
+            )
+            .unwrap();
+        let vs = Blob::compile_from_source(SHADERS, &[], c"VSMain", c"vs_5_0", 0, 0).unwrap();
+        let ps = Blob::compile_from_source(SHADERS, &[], c"PSMain", c"ps_5_0", 0, 0).unwrap();
+
+        for blend in [BlendDesc::opaque(), BlendDesc::alpha_blend()] {
+            let desc = GraphicsPipelineDesc::new(&vs)
+                .with_ps(&ps)
+                .with_root_signature(&root_signature)
+                .with_blend_desc(blend)
+                .with_primitive_topology(PipelinePrimitiveTopology::Triangle)
+                .with_render_targets([Format::Rgba8Unorm]);
+
+            assert!(device.create_graphics_pipeline(&desc).is_ok());
+        }
+    }
+}
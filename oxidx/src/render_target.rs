@@ -0,0 +1,110 @@
+use crate::{device::IDevice, error::DxError, resources::Resource, types::*};
+
+/// A committed 2D texture created together with its render target view and, optionally, a shader resource view.
+///
+/// The texture starts in [`ResourceStates::RenderTarget`] and is optimized for clears to transparent black.
+#[derive(Debug)]
+pub struct RenderTarget {
+    resource: Resource,
+    rtv: CpuDescriptorHandle,
+    srv: Option<GpuDescriptorHandle>,
+}
+
+impl RenderTarget {
+    /// Creates the texture and writes its views into `rtv` and, if given, into the `srv` slot of a shader-visible heap.
+    pub fn new(
+        device: &impl IDevice,
+        width: u32,
+        height: u32,
+        format: Format,
+        rtv: CpuDescriptorHandle,
+        srv: Option<(CpuDescriptorHandle, GpuDescriptorHandle)>,
+    ) -> Result<Self, DxError> {
+        let resource = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::texture_2d(width, height)
+                .with_format(format)
+                .with_flags(ResourceFlags::AllowRenderTarget),
+            ResourceStates::RenderTarget,
+            Some(&ClearValue::color(format, [0.0; 4])),
+        )?;
+
+        device.create_render_target_view(
+            Some(&resource),
+            Some(&RenderTargetViewDesc::texture_2d(format, 0, 0)),
+            rtv,
+        );
+
+        let srv = srv.map(|(cpu, gpu)| {
+            device.create_shader_resource_view(
+                Some(&resource),
+                Some(&ShaderResourceViewDesc::texture_2d(format, 0, 1, 0.0, 0)),
+                cpu,
+            );
+
+            gpu
+        });
+
+        Ok(Self { resource, rtv, srv })
+    }
+
+    #[inline]
+    pub fn resource(&self) -> &Resource {
+        &self.resource
+    }
+
+    #[inline]
+    pub fn rtv_handle(&self) -> CpuDescriptorHandle {
+        self.rtv
+    }
+
+    /// Returns `None` if the render target was created without a shader resource view.
+    #[inline]
+    pub fn srv_gpu_handle(&self) -> Option<GpuDescriptorHandle> {
+        self.srv
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn render_target_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let rtv_heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::rtv(1))
+            .unwrap();
+        let srv_heap = device
+            .create_descriptor_heap(
+                &DescriptorHeapDesc::cbr_srv_uav(1).with_flags(DescriptorHeapFlags::ShaderVisible),
+            )
+            .unwrap();
+
+        let target = RenderTarget::new(
+            &device,
+            512,
+            512,
+            Format::Rgba8Unorm,
+            rtv_heap.get_cpu_descriptor_handle_for_heap_start(),
+            Some((
+                srv_heap.get_cpu_descriptor_handle_for_heap_start(),
+                srv_heap.get_gpu_descriptor_handle_for_heap_start(),
+            )),
+        )
+        .unwrap();
+
+        assert_eq!(
+            target.rtv_handle(),
+            rtv_heap.get_cpu_descriptor_handle_for_heap_start()
+        );
+        assert_eq!(
+            target.srv_gpu_handle(),
+            Some(srv_heap.get_gpu_descriptor_handle_for_heap_start())
+        );
+        assert_eq!(target.resource().get_desc().width(), 512);
+        assert_eq!(target.resource().get_desc().height(), 512);
+    }
+}
@@ -1,4 +1,7 @@
-use std::ops::Range;
+use std::{
+    ops::{Deref, DerefMut, Range},
+    ptr::NonNull,
+};
 
 use windows::{
     core::{Interface, Param},
@@ -7,9 +10,13 @@ use windows::{
 
 use crate::{
     create_type,
+    device::IDevice,
     error::DxError,
     impl_trait,
-    types::{DxBox, GpuVirtualAddress, HeapFlags, HeapProperties, ResourceDesc},
+    types::{
+        DxBox, GpuVirtualAddress, HeapFlags, HeapProperties, ResourceDesc, ResourceDimension,
+        CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT,
+    },
     HasInterface,
 };
 
@@ -73,6 +80,32 @@ pub trait IResource:
         src_row_pitch: u32,
         src_depth_pitch: u32,
     ) -> Result<(), DxError>;
+
+    /// Maps the buffer, copies `data` at byte `offset` and unmaps it, reporting only the written bytes.
+    ///
+    /// Writes through its own mapping, so it must not be called while a [`MappedData`] over this buffer is alive,
+    /// which the `# Safety` contracts of [`Resource::map_data`] and [`IDevice::create_upload_buffer`](crate::device::IDevice::create_upload_buffer) forbid.
+    /// Returns [`DxError::InvalidArgs`] if the resource is not a buffer or the data does not fit.
+    fn write_slice<T: Copy>(
+        &self,
+        subresource: u32,
+        offset: usize,
+        data: &[T],
+    ) -> Result<(), DxError>;
+
+    /// Writes `data` as a constant buffer at byte `offset` of the buffer.
+    ///
+    /// `offset` must be a multiple of [`CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT`](crate::types::CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT)
+    /// and the buffer must hold `size_of::<T>()` rounded up to that alignment, since that is the size a view of the constants covers.
+    /// Returns [`DxError::InvalidArgs`] otherwise.
+    ///
+    /// Like [`IResource::write_slice`], it must not be called while a [`MappedData`] over this buffer is alive.
+    fn write_constant_buffer<T: Copy>(
+        &self,
+        subresource: u32,
+        offset: usize,
+        data: &T,
+    ) -> Result<(), DxError>;
 }
 
 create_type! {
@@ -83,9 +116,260 @@ create_type! {
     Resource wrap ID3D12Resource
 }
 
+impl Resource {
+    /// Number of bytes the resource occupies when laid out for copying: the width for buffers
+    /// and the total size of copyable footprints of all subresources for textures.
+    pub fn allocation_size(&self, device: &impl IDevice) -> u64 {
+        let desc = self.get_desc();
+
+        let array_size = match desc.dimension() {
+            ResourceDimension::Buffer => return desc.width(),
+            ResourceDimension::Texture3D => 1,
+            _ => desc.depth_or_array_size() as u32,
+        };
+
+        device.get_copyable_footprints(
+            &desc,
+            0..desc.mip_levels() * array_size,
+            0,
+            None,
+            None,
+            None,
+        ) as u64
+    }
+
+    /// Maps the buffer and returns a guard which unmaps it when dropped.
+    ///
+    /// `read_range` is the range the CPU may read, `None` meaning the whole buffer.
+    /// Returns [`DxError::InvalidArgs`] if the resource is not a buffer.
+    ///
+    /// # Safety
+    ///
+    /// Maps are reference counted, so the same buffer, including through clones of the resource, can be mapped again.
+    /// The caller must ensure that no other [`MappedData`] or pointer returned by [`IResource::map`] over this buffer
+    /// is accessed while the returned guard is alive, since the guard hands out `&mut [u8]` to the whole buffer.
+    pub unsafe fn map_data(
+        &self,
+        subresource: u32,
+        read_range: Option<Range<usize>>,
+    ) -> Result<MappedData, DxError> {
+        let desc = self.get_desc();
+
+        if desc.dimension() != ResourceDimension::Buffer {
+            return Err(DxError::InvalidArgs);
+        }
+
+        MappedData::new(self.clone(), subresource, desc.width() as usize, read_range)
+    }
+}
+
+/// A mapped subresource which is unmapped when dropped.
+///
+/// Unless narrowed with [`MappedData::set_written_range`], the whole subresource is reported as written on unmap.
+#[derive(Debug)]
+pub struct MappedData {
+    resource: Resource,
+    subresource: u32,
+    ptr: NonNull<u8>,
+    size: usize,
+    written: Option<Range<usize>>,
+}
+
+impl MappedData {
+    pub(crate) fn new(
+        resource: Resource,
+        subresource: u32,
+        size: usize,
+        read_range: Option<Range<usize>>,
+    ) -> Result<Self, DxError> {
+        let ptr = resource.map(subresource, read_range)?;
+
+        Ok(Self {
+            resource,
+            subresource,
+            ptr,
+            size,
+            written: None,
+        })
+    }
+
+    #[inline]
+    pub fn resource(&self) -> &Resource {
+        &self.resource
+    }
+
+    #[inline]
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.size) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.size) }
+    }
+
+    /// Sets the range passed to [`IResource::unmap`] on drop, an empty range meaning nothing was written.
+    #[inline]
+    pub fn set_written_range(&mut self, range: Range<usize>) {
+        debug_assert!(range.end <= self.size, "written range exceeds the mapping");
+
+        self.written = Some(range);
+    }
+}
+
+impl Deref for MappedData {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for MappedData {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl Drop for MappedData {
+    fn drop(&mut self) {
+        self.resource.unmap(self.subresource, self.written.take());
+    }
+}
+
+/// A buffer resource which carries its [`ResourceDesc`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Buffer(pub ID3D12Resource, ResourceDesc);
+
+impl Buffer {
+    #[inline]
+    pub fn desc(&self) -> &ResourceDesc {
+        &self.1
+    }
+
+    /// Size of the buffer in bytes.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.1.width()
+    }
+
+    #[inline]
+    pub fn gpu_va(&self) -> GpuVirtualAddress {
+        unsafe { self.0.GetGPUVirtualAddress() }
+    }
+}
+
+/// A texture resource of any dimension which carries its [`ResourceDesc`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Texture(pub ID3D12Resource, ResourceDesc);
+
+impl Texture {
+    #[inline]
+    pub fn desc(&self) -> &ResourceDesc {
+        &self.1
+    }
+
+    /// Number of subresources of the first plane, i.e. mip levels multiplied by array size.
+    #[inline]
+    pub fn subresource_count(&self) -> u32 {
+        let array_size = match self.1.dimension() {
+            ResourceDimension::Texture3D => 1,
+            _ => self.1.depth_or_array_size() as u32,
+        };
+
+        self.1.mip_levels() * array_size
+    }
+}
+
+macro_rules! impl_typed_resource {
+    ($name:ident, $($dimension:pat_param)|+) => {
+        impl HasInterface for $name {
+            type Raw = ID3D12Resource;
+            type RawRef<'a> = &'a ID3D12Resource;
+
+            /// Wraps `raw` without checking its dimension, use [`TryFrom<Resource>`] for a checked conversion.
+            ///
+            /// # Panics
+            /// In debug builds, if the dimension of `raw` doesn't match the wrapper.
+            #[inline]
+            fn new(raw: Self::Raw) -> Self {
+                let desc = unsafe { ResourceDesc(raw.GetDesc()) };
+
+                debug_assert!(
+                    matches!(desc.dimension(), $($dimension)|+),
+                    "{:?} resource can't be wrapped as {}",
+                    desc.dimension(),
+                    stringify!($name),
+                );
+
+                Self(raw, desc)
+            }
+
+            #[inline]
+            fn as_raw(&self) -> &Self::Raw {
+                &self.0
+            }
+
+            #[inline]
+            fn as_raw_ref(&self) -> Self::RawRef<'_> {
+                &self.0
+            }
+        }
+
+        impl From<$name> for Resource {
+            #[inline]
+            fn from(value: $name) -> Self {
+                Resource(value.0)
+            }
+        }
+
+        impl TryFrom<Resource> for $name {
+            type Error = DxError;
+
+            #[inline]
+            fn try_from(value: Resource) -> Result<Self, Self::Error> {
+                let desc = value.get_desc();
+
+                match desc.dimension() {
+                    $($dimension)|+ => Ok(Self(value.0, desc)),
+                    _ => Err(DxError::Cast(
+                        std::any::type_name::<Resource>(),
+                        std::any::type_name::<$name>(),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+impl_typed_resource!(Buffer, ResourceDimension::Buffer);
+impl_typed_resource!(
+    Texture,
+    ResourceDimension::Texture1D | ResourceDimension::Texture2D | ResourceDimension::Texture3D
+);
+
 impl_trait! {
     impl IResource =>
-    Resource;
+    Resource,
+    Buffer,
+    Texture;
 
     fn get_desc(&self) -> ResourceDesc {
         unsafe {
@@ -183,4 +467,220 @@ impl_trait! {
             ).map_err(DxError::from)
         }
     }
+
+    fn write_slice<T: Copy>(
+        &self,
+        subresource: u32,
+        offset: usize,
+        data: &[T],
+    ) -> Result<(), DxError> {
+        let desc = self.get_desc();
+        let size = std::mem::size_of_val(data);
+
+        if desc.dimension() != ResourceDimension::Buffer
+            || offset.checked_add(size).is_none_or(|end| end > desc.width() as usize)
+        {
+            return Err(DxError::InvalidArgs);
+        }
+
+        let ptr = self.map::<u8>(subresource, Some(0..0))?;
+        unsafe {
+            std::ptr::copy_nonoverlapping(data.as_ptr() as *const u8, ptr.as_ptr().add(offset), size);
+        }
+        self.unmap(subresource, Some(offset..offset + size));
+
+        Ok(())
+    }
+
+    fn write_constant_buffer<T: Copy>(
+        &self,
+        subresource: u32,
+        offset: usize,
+        data: &T,
+    ) -> Result<(), DxError> {
+        let alignment = CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT as usize;
+        let size = std::mem::size_of::<T>().next_multiple_of(alignment);
+
+        if !offset.is_multiple_of(alignment)
+            || offset
+                .checked_add(size)
+                .is_none_or(|end| end > self.get_desc().width() as usize)
+        {
+            return Err(DxError::InvalidArgs);
+        }
+
+        self.write_slice(subresource, offset, std::slice::from_ref(data))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn typed_resource_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let buffer: Buffer = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(1024),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(buffer.size(), 1024);
+        assert_eq!(buffer.gpu_va(), buffer.get_gpu_virtual_address());
+
+        let texture: Texture = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(64, 64)
+                    .with_format(Format::Rgba8Unorm)
+                    .with_array_size(6)
+                    .with_mip_levels(4),
+                ResourceStates::Common,
+                None,
+            )
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(texture.subresource_count(), 24);
+
+        let resource: Resource = texture.into();
+        assert!(Buffer::try_from(resource).is_err());
+    }
+
+    #[test]
+    fn allocation_size_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let buffer = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(1000),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(buffer.allocation_size(&device), 1000);
+
+        let desc = ResourceDesc::texture_2d(256, 256).with_format(Format::Rgba8Unorm);
+        let texture = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &desc,
+                ResourceStates::Common,
+                None,
+            )
+            .unwrap();
+
+        let size = texture.allocation_size(&device);
+        let info = device.get_resource_allocation_info(0, &[desc]);
+
+        assert_eq!(size, 256 * 256 * 4);
+        assert!(size <= info.size());
+    }
+
+    #[test]
+    fn map_data_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let buffer = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(256),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+
+        {
+            let mut mapped = unsafe { buffer.map_data(0, Some(0..0)) }.unwrap();
+            assert_eq!(mapped.len(), 256);
+
+            mapped[..4].copy_from_slice(&[1, 2, 3, 4]);
+            mapped.set_written_range(0..4);
+        }
+
+        let mapped = unsafe { buffer.map_data(0, None) }.unwrap();
+        assert_eq!(&mapped[..4], &[1, 2, 3, 4]);
+        drop(mapped);
+
+        let texture = device
+            .create_committed_resource(
+                &HeapProperties::default(),
+                HeapFlags::empty(),
+                &ResourceDesc::texture_2d(4, 4).with_format(Format::Rgba8Unorm),
+                ResourceStates::Common,
+                None,
+            )
+            .unwrap();
+        assert!(matches!(
+            unsafe { texture.map_data(0, None) },
+            Err(DxError::InvalidArgs)
+        ));
+    }
+
+    #[test]
+    fn write_constant_buffer_test() {
+        #[derive(Clone, Copy)]
+        #[repr(C)]
+        struct Constants {
+            color: [f32; 4],
+            scale: f32,
+        }
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let buffer = device
+            .create_committed_resource(
+                &HeapProperties::upload(),
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(512),
+                ResourceStates::GenericRead,
+                None,
+            )
+            .unwrap();
+
+        let constants = Constants {
+            color: [1.0, 0.5, 0.25, 1.0],
+            scale: 2.0,
+        };
+
+        buffer.write_constant_buffer(0, 256, &constants).unwrap();
+        buffer.write_slice(0, 0, &[7u32, 8, 9]).unwrap();
+
+        assert!(matches!(
+            buffer.write_constant_buffer(0, 128, &constants),
+            Err(DxError::InvalidArgs)
+        ));
+        assert!(matches!(
+            buffer.write_constant_buffer(0, 512, &constants),
+            Err(DxError::InvalidArgs)
+        ));
+        assert!(matches!(
+            buffer.write_slice(0, 508, &[0u32; 2]),
+            Err(DxError::InvalidArgs)
+        ));
+        assert!(matches!(
+            buffer.write_slice(0, usize::MAX, &[0u32; 2]),
+            Err(DxError::InvalidArgs)
+        ));
+
+        let mapped = unsafe { buffer.map_data(0, None) }.unwrap();
+        let expected: Vec<u8> = [7u32, 8, 9].iter().flat_map(|v| v.to_ne_bytes()).collect();
+        assert_eq!(&mapped[..12], &expected[..]);
+        assert_eq!(&mapped[256 + 16..256 + 20], &2.0f32.to_ne_bytes());
+    }
 }
@@ -17,7 +17,10 @@ pub trait IRootSignature:
 
 /// Additional methods
 pub trait IRootSignatureExt: IRootSignature {
-    /// Serializes a root signature version 1.0
+    /// Serializes a root signature.
+    ///
+    /// Versions after 1.0 go through `D3D12SerializeVersionedRootSignature`, parameters keep the volatile
+    /// semantics of version 1.0 and static samplers get no flags.
     ///
     /// For more information: [`D3D12SerializeRootSignature function`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-d3d12serializerootsignature)
     fn serialize(
@@ -48,12 +51,15 @@ impl_trait! {
         let mut signature = None;
 
         let signature = unsafe {
-            D3D12SerializeRootSignature(
-                &desc.0,
-                version.as_raw(),
-                &mut signature,
-                None,
-            )
+            match version {
+                RootSignatureVersion::V1_0 => D3D12SerializeRootSignature(
+                    &desc.0,
+                    version.as_raw(),
+                    &mut signature,
+                    None,
+                ),
+                _ => serialize_versioned(desc, version, &mut signature),
+            }
         }
         .map(|()| signature.unwrap())
         .map_err(DxError::from)?;
@@ -61,3 +67,205 @@ impl_trait! {
         Ok(Blob::new(signature))
     }
 }
+
+/// Converts a version 1.0 description and serializes it as `version`, which must be 1.1 or later.
+unsafe fn serialize_versioned(
+    desc: &RootSignatureDesc<'_>,
+    version: RootSignatureVersion,
+    signature: &mut Option<windows::Win32::Graphics::Direct3D::ID3DBlob>,
+) -> windows::core::Result<()> {
+    let desc = &desc.0;
+
+    let parameters = if desc.NumParameters > 0 {
+        std::slice::from_raw_parts(desc.pParameters, desc.NumParameters as usize)
+    } else {
+        &[]
+    };
+    let samplers = if desc.NumStaticSamplers > 0 {
+        std::slice::from_raw_parts(desc.pStaticSamplers, desc.NumStaticSamplers as usize)
+    } else {
+        &[]
+    };
+
+    let ranges = parameters
+        .iter()
+        .map(|parameter| {
+            if parameter.ParameterType != D3D12_ROOT_PARAMETER_TYPE_DESCRIPTOR_TABLE {
+                return vec![];
+            }
+
+            let table = parameter.Anonymous.DescriptorTable;
+            if table.NumDescriptorRanges == 0 {
+                return vec![];
+            }
+
+            std::slice::from_raw_parts(table.pDescriptorRanges, table.NumDescriptorRanges as usize)
+                .iter()
+                .map(|range| D3D12_DESCRIPTOR_RANGE1 {
+                    RangeType: range.RangeType,
+                    NumDescriptors: range.NumDescriptors,
+                    BaseShaderRegister: range.BaseShaderRegister,
+                    RegisterSpace: range.RegisterSpace,
+                    Flags: if range.RangeType == D3D12_DESCRIPTOR_RANGE_TYPE_SAMPLER {
+                        D3D12_DESCRIPTOR_RANGE_FLAG_DESCRIPTORS_VOLATILE
+                    } else {
+                        D3D12_DESCRIPTOR_RANGE_FLAG_DESCRIPTORS_VOLATILE
+                            | D3D12_DESCRIPTOR_RANGE_FLAG_DATA_VOLATILE
+                    },
+                    OffsetInDescriptorsFromTableStart: range.OffsetInDescriptorsFromTableStart,
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<_>>>();
+
+    let parameters = parameters
+        .iter()
+        .zip(&ranges)
+        .map(|(parameter, ranges)| {
+            let anonymous = match parameter.ParameterType {
+                D3D12_ROOT_PARAMETER_TYPE_DESCRIPTOR_TABLE => D3D12_ROOT_PARAMETER1_0 {
+                    DescriptorTable: D3D12_ROOT_DESCRIPTOR_TABLE1 {
+                        NumDescriptorRanges: ranges.len() as u32,
+                        pDescriptorRanges: ranges.as_ptr(),
+                    },
+                },
+                D3D12_ROOT_PARAMETER_TYPE_32BIT_CONSTANTS => D3D12_ROOT_PARAMETER1_0 {
+                    Constants: parameter.Anonymous.Constants,
+                },
+                _ => D3D12_ROOT_PARAMETER1_0 {
+                    Descriptor: D3D12_ROOT_DESCRIPTOR1 {
+                        ShaderRegister: parameter.Anonymous.Descriptor.ShaderRegister,
+                        RegisterSpace: parameter.Anonymous.Descriptor.RegisterSpace,
+                        Flags: D3D12_ROOT_DESCRIPTOR_FLAG_DATA_VOLATILE,
+                    },
+                },
+            };
+
+            D3D12_ROOT_PARAMETER1 {
+                ParameterType: parameter.ParameterType,
+                Anonymous: anonymous,
+                ShaderVisibility: parameter.ShaderVisibility,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let samplers = samplers
+        .iter()
+        .map(|sampler| D3D12_STATIC_SAMPLER_DESC1 {
+            Filter: sampler.Filter,
+            AddressU: sampler.AddressU,
+            AddressV: sampler.AddressV,
+            AddressW: sampler.AddressW,
+            MipLODBias: sampler.MipLODBias,
+            MaxAnisotropy: sampler.MaxAnisotropy,
+            ComparisonFunc: sampler.ComparisonFunc,
+            BorderColor: sampler.BorderColor,
+            MinLOD: sampler.MinLOD,
+            MaxLOD: sampler.MaxLOD,
+            ShaderRegister: sampler.ShaderRegister,
+            RegisterSpace: sampler.RegisterSpace,
+            ShaderVisibility: sampler.ShaderVisibility,
+            Flags: D3D12_SAMPLER_FLAG_NONE,
+        })
+        .collect::<Vec<_>>();
+
+    let anonymous = match version {
+        RootSignatureVersion::V1_1 => D3D12_VERSIONED_ROOT_SIGNATURE_DESC_0 {
+            Desc_1_1: D3D12_ROOT_SIGNATURE_DESC1 {
+                NumParameters: parameters.len() as u32,
+                pParameters: parameters.as_ptr(),
+                NumStaticSamplers: desc.NumStaticSamplers,
+                pStaticSamplers: desc.pStaticSamplers,
+                Flags: desc.Flags,
+            },
+        },
+        _ => D3D12_VERSIONED_ROOT_SIGNATURE_DESC_0 {
+            Desc_1_2: D3D12_ROOT_SIGNATURE_DESC2 {
+                NumParameters: parameters.len() as u32,
+                pParameters: parameters.as_ptr(),
+                NumStaticSamplers: samplers.len() as u32,
+                pStaticSamplers: samplers.as_ptr(),
+                Flags: desc.Flags,
+            },
+        },
+    };
+
+    let versioned = D3D12_VERSIONED_ROOT_SIGNATURE_DESC {
+        Version: version.as_raw(),
+        Anonymous: anonymous,
+    };
+
+    D3D12SerializeVersionedRootSignature(&versioned, signature, None)
+}
+
+#[cfg(test)]
+mod test {
+    use windows::Win32::Graphics::Direct3D12::D3D12_STATIC_BORDER_COLOR_OPAQUE_WHITE_UINT;
+
+    use crate::dx::*;
+
+    #[test]
+    fn static_sampler_border_color_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let samplers = [StaticSamplerDesc::point()
+            .with_address_u(AddressMode::Border)
+            .with_address_v(AddressMode::Border)
+            .with_address_w(AddressMode::Border)
+            .with_border_color(BorderColor::OpaqueWhite)];
+        let root_signature = device.serialize_and_create_root_signature(
+            &RootSignatureDesc::default().with_samplers(&samplers),
+            RootSignatureVersion::V1_0,
+            0,
+        );
+        assert!(root_signature.is_ok());
+
+        let mut feature = features::RootSignatureFeature::new(RootSignatureVersion::V1_2);
+        device.check_feature_support(&mut feature).unwrap();
+
+        if feature.highest_version() != RootSignatureVersion::V1_2 {
+            return;
+        }
+
+        let samplers = [samplers[0].with_border_color(BorderColor::OpaqueWhiteUint)];
+        assert_eq!(samplers[0].border_color(), BorderColor::OpaqueWhiteUint);
+        assert_eq!(
+            BorderColor::OpaqueWhiteUint.as_raw(),
+            D3D12_STATIC_BORDER_COLOR_OPAQUE_WHITE_UINT
+        );
+
+        let root_signature = device.serialize_and_create_root_signature(
+            &RootSignatureDesc::default().with_samplers(&samplers),
+            RootSignatureVersion::V1_2,
+            0,
+        );
+        assert!(root_signature.is_ok());
+    }
+
+    #[test]
+    fn root_parameter_constructors_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let srv_range = DescriptorRange::srv(2, 0);
+        let sampler_range = DescriptorRange::sampler(1, 0);
+        let parameters = [
+            RootParameter::descriptor_table_single(&srv_range)
+                .with_visibility(ShaderVisibility::Pixel),
+            RootParameter::descriptor_table_single(&sampler_range)
+                .with_visibility(ShaderVisibility::Pixel),
+            RootParameter::cbv(0, 0),
+            RootParameter::srv(2, 0).with_visibility(ShaderVisibility::Vertex),
+            RootParameter::uav(0, 0),
+            RootParameter::constant_32bit(1, 0, 4),
+        ];
+
+        let root_signature = device.serialize_and_create_root_signature(
+            &RootSignatureDesc::default()
+                .with_parameters(&parameters)
+                .with_flags(RootSignatureFlags::AllowInputAssemblerInputLayout),
+            RootSignatureVersion::V1_0,
+            0,
+        );
+        assert!(root_signature.is_ok());
+    }
+}
@@ -0,0 +1,111 @@
+use std::{marker::PhantomData, mem::size_of};
+
+use crate::{
+    device::IDevice,
+    error::DxError,
+    resources::{IResource, Resource},
+    types::*,
+};
+
+/// A committed buffer of `T` elements usable as a structured buffer by compute and graphics shaders.
+///
+/// The buffer lives in the default heap, allows unordered access and starts in [`ResourceStates::Common`].
+#[derive(Debug)]
+pub struct StructuredBuffer<T> {
+    resource: Resource,
+    count: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> StructuredBuffer<T> {
+    /// Creates a buffer holding `count` elements.
+    pub fn new(device: &impl IDevice, count: usize) -> Result<Self, DxError> {
+        let resource = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::buffer(count * size_of::<T>())
+                .with_flags(ResourceFlags::AllowUnorderedAccess),
+            ResourceStates::Common,
+            None,
+        )?;
+
+        Ok(Self {
+            resource,
+            count,
+            _marker: PhantomData,
+        })
+    }
+
+    #[inline]
+    pub fn resource(&self) -> &Resource {
+        &self.resource
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Size of the buffer in bytes.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        self.count * size_of::<T>()
+    }
+
+    /// GPU virtual address of the element at `index`, for root descriptors.
+    #[inline]
+    pub fn element_gpu_va(&self, index: usize) -> GpuVirtualAddress {
+        debug_assert!(index < self.count, "element index out of bounds");
+
+        self.resource.get_gpu_virtual_address() + (index * size_of::<T>()) as GpuVirtualAddress
+    }
+
+    /// Describes an unordered access view over `count` elements starting at `first`.
+    #[inline]
+    pub fn make_uav_desc(&self, first: usize, count: usize) -> UnorderedAccessViewDesc {
+        debug_assert!(first + count <= self.count, "view exceeds the buffer");
+
+        UnorderedAccessViewDesc::buffer(
+            Format::Unknown,
+            first..first + count,
+            size_of::<T>(),
+            0,
+            BufferUavFlags::empty(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn structured_buffer_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let buffer = StructuredBuffer::<[f32; 4]>::new(&device, 1024).unwrap();
+
+        assert_eq!(buffer.len(), 1024);
+        assert_eq!(buffer.byte_size(), 16 * 1024);
+        assert_eq!(buffer.resource().get_desc().width(), 16 * 1024);
+        assert_eq!(
+            buffer.element_gpu_va(3),
+            buffer.resource().get_gpu_virtual_address() + 48
+        );
+
+        let heap = device
+            .create_descriptor_heap(&DescriptorHeapDesc::cbr_srv_uav(1))
+            .unwrap();
+        device.create_unordered_access_view(
+            Some(buffer.resource()),
+            RES_NONE,
+            Some(&buffer.make_uav_desc(0, 1024)),
+            heap.get_cpu_descriptor_handle_for_heap_start(),
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use windows::core::{Interface, Param};
+use windows::Win32::Graphics::Direct3D12::{ID3D12CommandQueue, ID3D12Device};
 use windows::Win32::Graphics::Dxgi::{
     IDXGIOutput, IDXGIOutput1, IDXGISwapChain1, IDXGISwapChain2, IDXGISwapChain3,
+    DXGI_ERROR_DEVICE_REMOVED, DXGI_ERROR_DEVICE_RESET,
 };
 
 use crate::error::DxError;
@@ -20,6 +22,9 @@ pub trait ISwapchain1: HasInterface {
 
     /// Presents a rendered image to the user.
     ///
+    /// If the device was removed or reset, returns [`DxError::DeviceRemoved`] with the reason
+    /// reported by the device which owns the swap chain's command queue.
+    ///
     /// For more information: [`IDXGISwapChain::Present method`](https://learn.microsoft.com/en-us/windows/win32/api/dxgi/nf-dxgi-idxgiswapchain-present)
     fn present(&self, interval: u32, flags: PresentFlags) -> Result<(), DxError>;
 
@@ -70,6 +75,21 @@ create_type! {
     Swapchain3 wrap IDXGISwapChain3; decorator for Swapchain2, Swapchain1
 }
 
+/// D3D12 swap chains are created over a command queue, so the removal reason is taken from the queue's device.
+fn device_removed_reason(swapchain: &IDXGISwapChain1) -> Option<String> {
+    unsafe {
+        let queue = swapchain.GetDevice::<ID3D12CommandQueue>().ok()?;
+
+        let mut device = None;
+        queue.GetDevice::<ID3D12Device>(&mut device).ok()?;
+
+        device?
+            .GetDeviceRemovedReason()
+            .err()
+            .map(|err| err.message())
+    }
+}
+
 impl_trait! {
     impl ISwapchain1 =>
     Swapchain1,
@@ -86,7 +106,15 @@ impl_trait! {
 
     fn present(&self, interval: u32, flags: PresentFlags) -> Result<(), DxError> {
         unsafe {
-            self.0.Present(interval, flags.as_raw()).ok().map_err(DxError::from)
+            let hr = self.0.Present(interval, flags.as_raw());
+
+            if hr == DXGI_ERROR_DEVICE_REMOVED || hr == DXGI_ERROR_DEVICE_RESET {
+                if let Some(reason) = device_removed_reason(&self.0) {
+                    return Err(DxError::DeviceRemoved(reason));
+                }
+            }
+
+            hr.ok().map_err(DxError::from)
         }
     }
 
@@ -189,3 +217,31 @@ impl_trait! {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn present_test() {
+        let factory = create_factory4(FactoryCreationFlags::empty()).unwrap();
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+
+        let desc = SwapchainDesc1::new(64, 64)
+            .with_format(Format::Bgra8Unorm)
+            .with_usage(FrameBufferUsage::RenderTargetOutput)
+            .with_buffer_count(2)
+            .with_scaling(Scaling::Stretch)
+            .with_swap_effect(SwapEffect::FlipDiscard);
+
+        let swapchain = factory
+            .create_swapchain_for_composition(&queue, &desc, None::<&Output1>)
+            .unwrap();
+
+        // A removed device would be reported as `DxError::DeviceRemoved` with the device's removal reason.
+        assert!(swapchain.present(0, PresentFlags::empty()).is_ok());
+    }
+}
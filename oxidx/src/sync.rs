@@ -1,9 +1,14 @@
+use std::{mem::ManuallyDrop, time::Duration};
+
+use smallvec::SmallVec;
 use windows::{
     core::{Interface, Param},
     Win32::{
-        Foundation::{CloseHandle, HANDLE},
+        Foundation::{CloseHandle, HANDLE, WAIT_EVENT, WAIT_OBJECT_0, WAIT_TIMEOUT},
         Graphics::Direct3D12::{ID3D12Fence, ID3D12Fence1},
-        System::Threading::{CreateEventA, ResetEvent, WaitForSingleObject},
+        System::Threading::{
+            CreateEventA, ResetEvent, WaitForMultipleObjects, WaitForSingleObject, INFINITE,
+        },
     },
 };
 
@@ -21,12 +26,25 @@ pub trait IFence: for<'a> HasInterface<Raw: Interface, RawRef<'a>: Param<ID3D12F
     /// Specifies an event that's raised when the fence reaches a certain value.
     ///
     /// For more information: [`ID3D12Fence::SetEventOnCompletion method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12fence-seteventoncompletion)
-    fn set_event_on_completion(&self, value: u64, event: Event) -> Result<(), DxError>;
+    fn set_event_on_completion(&self, value: u64, event: &Event) -> Result<(), DxError>;
 
     /// Sets the fence to the specified value.
     ///
     /// For more information: [`ID3D12Fence::Signal method`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/nf-d3d12-id3d12fence-signal)
     fn signal(&self, value: u64) -> Result<(), DxError>;
+
+    /// Blocks the calling thread until the fence reaches `value`.
+    ///
+    /// Returns right away if the value is already reached, otherwise waits on a temporary [`Event`].
+    fn wait(&self, value: u64) -> Result<(), DxError>;
+
+    /// Blocks the calling thread until the fence reaches `value`, polling the completed value up to `spin_iters` times
+    /// before falling back to a temporary [`Event`].
+    ///
+    /// Spinning avoids the cost of an event for waits expected to finish within a few microseconds.
+    /// Returns [`DxError::Timeout`] if the event wait exceeds `timeout`.
+    fn wait_for_value(&self, value: u64, spin_iters: u32, timeout: Duration)
+        -> Result<(), DxError>;
 }
 
 /// Represents a fence. This interface extends [`IFence1`], and supports the retrieval of the flags used to create the original fence.
@@ -64,7 +82,7 @@ impl_trait! {
         unsafe { self.0.GetCompletedValue() }
     }
 
-    fn set_event_on_completion(&self, value: u64, event: Event) -> Result<(), DxError> {
+    fn set_event_on_completion(&self, value: u64, event: &Event) -> Result<(), DxError> {
         unsafe {
             self.0.SetEventOnCompletion(value, event.0).map_err(DxError::from)
         }
@@ -73,6 +91,45 @@ impl_trait! {
     fn signal(&self, value: u64) -> Result<(), DxError> {
         unsafe { self.0.Signal(value).map_err(DxError::from) }
     }
+
+    fn wait(&self, value: u64) -> Result<(), DxError> {
+        if self.get_completed_value() >= value {
+            return Ok(());
+        }
+
+        let event = Event::create(false, false)?;
+        self.set_event_on_completion(value, &event)?;
+
+        match WAIT_EVENT(event.wait(INFINITE)) {
+            WAIT_OBJECT_0 => Ok(()),
+            _ => Err(windows::core::Error::from_win32().into()),
+        }
+    }
+
+    fn wait_for_value(&self, value: u64, spin_iters: u32, timeout: Duration) -> Result<(), DxError> {
+        for _ in 0..spin_iters {
+            if self.get_completed_value() >= value {
+                return Ok(());
+            }
+
+            std::hint::spin_loop();
+        }
+
+        if self.get_completed_value() >= value {
+            return Ok(());
+        }
+
+        let timeout = u32::try_from(timeout.as_millis()).unwrap_or(INFINITE);
+
+        let event = Event::create(false, false)?;
+        self.set_event_on_completion(value, &event)?;
+
+        match WAIT_EVENT(event.wait(timeout)) {
+            WAIT_OBJECT_0 => Ok(()),
+            WAIT_TIMEOUT => Err(DxError::Timeout),
+            _ => Err(windows::core::Error::from_win32().into()),
+        }
+    }
 }
 
 impl_trait! {
@@ -86,14 +143,17 @@ impl_trait! {
     }
 }
 
-/// A handle to the object of event.
-#[derive(Clone, Copy, Debug)]
+/// An owned handle to the object of event, which is closed when dropped.
+#[derive(Debug)]
 pub struct Event(pub(crate) HANDLE);
 impl Event {
     /// Creates or opens a named or unnamed event object.
     ///
     /// For more information: [`CreateEventA`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventa)
     pub fn create(manual_reset: bool, initial_state: bool) -> Result<Self, DxError> {
+        #[cfg(test)]
+        test::EVENTS_CREATED.with(|count| count.set(count.get() + 1));
+
         Ok(Event(unsafe {
             CreateEventA(None, manual_reset, initial_state, None).map_err(DxError::from)?
         }))
@@ -113,10 +173,131 @@ impl Event {
         unsafe { WaitForSingleObject(self.0, timeout_ms).0 }
     }
 
-    /// Closes an open object handle.
+    /// Waits until all or any of `events` are signaled, or `timeout` elapses.
+    ///
+    /// Returns the index of a signaled event, with `wait_all` any index of `events` may be returned.
+    /// Returns [`DxError::Timeout`] if the time-out interval elapsed first.
+    ///
+    /// For more information: [`WaitForMultipleObjects`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitformultipleobjects)
+    pub fn wait_multiple(
+        events: &[&Event],
+        wait_all: bool,
+        timeout: Duration,
+    ) -> Result<usize, DxError> {
+        let handles: SmallVec<[HANDLE; 8]> = events.iter().map(|event| event.0).collect();
+        let timeout = u32::try_from(timeout.as_millis()).unwrap_or(INFINITE);
+
+        let result = unsafe { WaitForMultipleObjects(&handles, wait_all, timeout) };
+
+        match result {
+            WAIT_TIMEOUT => Err(DxError::Timeout),
+            _ if (result.0.wrapping_sub(WAIT_OBJECT_0.0) as usize) < handles.len() => {
+                Ok((result.0 - WAIT_OBJECT_0.0) as usize)
+            }
+            _ => Err(windows::core::Error::from_win32().into()),
+        }
+    }
+
+    /// Closes the handle, reporting the error [`Drop`] would ignore.
     ///
     /// For more information: [`CloseHandle function`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-closehandle)
     pub fn close(self) -> Result<(), DxError> {
-        unsafe { CloseHandle(self.0).map_err(DxError::from) }
+        unsafe { CloseHandle(ManuallyDrop::new(self).0).map_err(DxError::from) }
+    }
+}
+
+impl Drop for Event {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CloseHandle(self.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, time::Duration};
+
+    use crate::dx::*;
+
+    thread_local! {
+        pub(super) static EVENTS_CREATED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[test]
+    fn wait_multiple_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let direct = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let compute = device
+            .create_command_queue(&CommandQueueDesc::compute())
+            .unwrap();
+
+        let direct_fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        let compute_fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+        let direct_event = Event::create(false, false).unwrap();
+        let compute_event = Event::create(false, false).unwrap();
+
+        assert!(matches!(
+            Event::wait_multiple(&[&direct_event], true, Duration::ZERO),
+            Err(DxError::Timeout)
+        ));
+
+        direct_fence
+            .set_event_on_completion(1, &direct_event)
+            .unwrap();
+        compute_fence
+            .set_event_on_completion(1, &compute_event)
+            .unwrap();
+        direct.signal(&direct_fence, 1).unwrap();
+        compute.signal(&compute_fence, 1).unwrap();
+
+        let index = Event::wait_multiple(
+            &[&direct_event, &compute_event],
+            true,
+            Duration::from_secs(10),
+        )
+        .unwrap();
+        assert!(index < 2);
+        assert_eq!(direct_fence.get_completed_value(), 1);
+        assert_eq!(compute_fence.get_completed_value(), 1);
+    }
+
+    #[test]
+    fn fence_wait_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let queue = device
+            .create_command_queue(&CommandQueueDesc::direct())
+            .unwrap();
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+
+        queue.signal(&fence, 1).unwrap();
+        fence.wait(1).unwrap();
+        assert!(fence.get_completed_value() >= 1);
+
+        fence.signal(5).unwrap();
+        fence.wait(3).unwrap();
+        assert_eq!(fence.get_completed_value(), 5);
+    }
+
+    #[test]
+    fn wait_for_value_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+
+        let events = EVENTS_CREATED.get();
+
+        fence.signal(1).unwrap();
+        fence
+            .wait_for_value(1, 64, Duration::from_secs(10))
+            .unwrap();
+        assert_eq!(EVENTS_CREATED.get(), events);
+
+        assert!(matches!(
+            fence.wait_for_value(2, 0, Duration::ZERO),
+            Err(DxError::Timeout)
+        ));
+        assert_eq!(EVENTS_CREATED.get(), events + 1);
     }
 }
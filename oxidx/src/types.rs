@@ -16,12 +16,15 @@ use windows::Win32::Graphics::{
     Dxgi::{Common::*, *},
 };
 
-use crate::dx::{Adapter3, Output1, PipelineState, Resource};
+use crate::dx::{Adapter3, Heap, Output1, PipelineState, Resource};
 
 pub const MIN_DEPTH: f32 = D3D12_MIN_DEPTH;
 pub const MAX_DEPTH: f32 = D3D12_MAX_DEPTH;
 pub const BARRIER_ALL_SUBRESOURCES: u32 = D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES;
 pub const TEXTURE_DATA_PITCH_ALIGNMENT: u32 = D3D12_TEXTURE_DATA_PITCH_ALIGNMENT;
+pub const CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT: u32 =
+    D3D12_CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT;
+pub const TILED_RESOURCE_TILE_SIZE_IN_BYTES: u32 = D3D12_TILED_RESOURCE_TILE_SIZE_IN_BYTES;
 pub const APPEND_ALIGNED_ELEMENT: u32 = D3D12_APPEND_ALIGNED_ELEMENT;
 
 pub const COMPILE_DEBUG: u32 = D3DCOMPILE_DEBUG;
@@ -34,6 +37,7 @@ pub const ADAPTER_NONE: Option<&Adapter3> = None;
 pub const PSO_NONE: Option<&PipelineState> = None;
 pub const OUTPUT_NONE: Option<&Output1> = None;
 pub const RES_NONE: Option<&Resource> = None;
+pub const HEAP_NONE: Option<&Heap> = None;
 
 pub type GpuVirtualAddress = u64;
 pub type CallbackData =
@@ -157,10 +157,14 @@ pub enum BorderColor {
     /// Indicates white, with the alpha component as fully opaque.
     OpaqueWhite = D3D12_STATIC_BORDER_COLOR_OPAQUE_WHITE.0,
 
-    /// TBD
+    /// Indicates black, with the alpha component as fully opaque, for samplers of integer formats.
+    ///
+    /// Requires root signature version [`RootSignatureVersion::V1_2`].
     OpaqueBlackUint = D3D12_STATIC_BORDER_COLOR_OPAQUE_BLACK_UINT.0,
 
-    /// TBD
+    /// Indicates white, with the alpha component as fully opaque, for samplers of integer formats.
+    ///
+    /// Requires root signature version [`RootSignatureVersion::V1_2`].
     OpaqueWhiteUint = D3D12_STATIC_BORDER_COLOR_OPAQUE_WHITE_UINT.0,
 }
 
@@ -403,6 +407,21 @@ pub enum DescriptorRangeType {
     Sampler = D3D12_DESCRIPTOR_RANGE_TYPE_SAMPLER.0,
 }
 
+/// Identifies the type of resource to view as a depth-stencil.
+///
+/// For more information: [`D3D12_DSV_DIMENSION enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ne-d3d12-d3d12_dsv_dimension)
+#[derive(Clone, Copy, Debug, FromRepr, Hash, PartialEq, Eq)]
+#[repr(i32)]
+pub enum DsvDimension {
+    Unknown = D3D12_DSV_DIMENSION_UNKNOWN.0,
+    Texture1D = D3D12_DSV_DIMENSION_TEXTURE1D.0,
+    Texture1DArray = D3D12_DSV_DIMENSION_TEXTURE1DARRAY.0,
+    Texture2D = D3D12_DSV_DIMENSION_TEXTURE2D.0,
+    Texture2DArray = D3D12_DSV_DIMENSION_TEXTURE2DARRAY.0,
+    Texture2DMS = D3D12_DSV_DIMENSION_TEXTURE2DMS.0,
+    Texture2DMSArray = D3D12_DSV_DIMENSION_TEXTURE2DMSARRAY.0,
+}
+
 /// Describes the set of features targeted by a Direct3D device.
 ///
 /// For more information: [`D3D_FEATURE_LEVEL enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3dcommon/ne-d3dcommon-d3d_feature_level)
@@ -777,6 +796,10 @@ pub enum Format {
     /// A 32-bit typeless component, and two unsigned-integer components (with an additional 32 bits). This format has 32 bits unused, 8 bits for green channel, and 24 bits are unused.
     R32FloatX8X24Typeless = DXGI_FORMAT_R32_FLOAT_X8X24_TYPELESS.0,
 
+    /// A 64-bit format with 32 typeless bits unused, an 8-bit unsigned-integer stencil in the green channel, and 24 bits unused.
+    /// Used to read the stencil of a [`Format::D32FloatS8X24Uint`] resource.
+    X32TypelessG8X24Uint = DXGI_FORMAT_X32_TYPELESS_G8X24_UINT.0,
+
     /// A four-component, 32-bit typeless format that supports 10 bits for each color and 2 bits for alpha.
     Rgb10A2Typeless = DXGI_FORMAT_R10G10B10A2_TYPELESS.0,
 
@@ -1066,6 +1089,49 @@ pub enum Format {
 
     /// An 8 bit YCbCrA 4:4:4:4 rendering format.
     V408 = DXGI_FORMAT_V408.0,
+
+    /// An opaque format of a sampler feedback map which stores the minimum sampled mip level.
+    SamplerFeedbackMinMipOpaque = DXGI_FORMAT_SAMPLER_FEEDBACK_MIN_MIP_OPAQUE.0,
+
+    /// An opaque format of a sampler feedback map which stores whether a mip region was sampled.
+    SamplerFeedbackMipRegionUsedOpaque = DXGI_FORMAT_SAMPLER_FEEDBACK_MIP_REGION_USED_OPAQUE.0,
+
+    /// A four-component, 16-bit unsigned-normalized integer format that supports 4 bits for each channel including alpha, with alpha in the least significant bits.
+    Abgr4Unorm = DXGI_FORMAT_A4B4G4R4_UNORM.0,
+}
+
+impl Format {
+    /// The SRGB counterpart of an 8-bit RGBA/BGRA or BC format, `None` if it has none.
+    ///
+    /// Flip-model swapchains can't be created with SRGB formats, but their back buffers can still be viewed through an SRGB render target view.
+    #[inline]
+    pub fn as_srgb(&self) -> Option<Format> {
+        match self {
+            Format::Rgba8Unorm | Format::Rgba8UnormSrgb => Some(Format::Rgba8UnormSrgb),
+            Format::Bgra8Unorm | Format::Bgra8UnormSrgb => Some(Format::Bgra8UnormSrgb),
+            Format::Bgrx8Unorm | Format::Bgrx8UnormSrgb => Some(Format::Bgrx8UnormSrgb),
+            Format::Bc1Unorm | Format::Bc1UnormSrgb => Some(Format::Bc1UnormSrgb),
+            Format::Bc2Unorm | Format::Bc2UnormSrgb => Some(Format::Bc2UnormSrgb),
+            Format::Bc3Unorm | Format::Bc3UnormSrgb => Some(Format::Bc3UnormSrgb),
+            Format::Bc7Unorm | Format::Bc7UnormSrgb => Some(Format::Bc7UnormSrgb),
+            _ => None,
+        }
+    }
+
+    /// The linear counterpart of an SRGB format, any other format is returned unchanged.
+    #[inline]
+    pub fn as_linear(&self) -> Format {
+        match self {
+            Format::Rgba8UnormSrgb => Format::Rgba8Unorm,
+            Format::Bgra8UnormSrgb => Format::Bgra8Unorm,
+            Format::Bgrx8UnormSrgb => Format::Bgrx8Unorm,
+            Format::Bc1UnormSrgb => Format::Bc1Unorm,
+            Format::Bc2UnormSrgb => Format::Bc2Unorm,
+            Format::Bc3UnormSrgb => Format::Bc3Unorm,
+            Format::Bc7UnormSrgb => Format::Bc7Unorm,
+            format => *format,
+        }
+    }
 }
 
 /// The preference of GPU for the app to run on.
@@ -3058,7 +3124,7 @@ pub enum RootSignatureVersion {
     /// Version 1.1 of root signature layout.
     V1_1 = D3D_ROOT_SIGNATURE_VERSION_1_1.0,
 
-    /// TBD
+    /// Version 1.2 of root signature layout, adds static sampler flags and integer border colors.
     V1_2 = D3D_ROOT_SIGNATURE_VERSION_1_2.0,
 }
 
@@ -3085,6 +3151,23 @@ pub enum RotationMode {
     Rotate270 = DXGI_MODE_ROTATION_ROTATE270.0,
 }
 
+/// Identifies the type of resource to view as a render target.
+///
+/// For more information: [`D3D12_RTV_DIMENSION enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ne-d3d12-d3d12_rtv_dimension)
+#[derive(Clone, Copy, Debug, FromRepr, Hash, PartialEq, Eq)]
+#[repr(i32)]
+pub enum RtvDimension {
+    Unknown = D3D12_RTV_DIMENSION_UNKNOWN.0,
+    Buffer = D3D12_RTV_DIMENSION_BUFFER.0,
+    Texture1D = D3D12_RTV_DIMENSION_TEXTURE1D.0,
+    Texture1DArray = D3D12_RTV_DIMENSION_TEXTURE1DARRAY.0,
+    Texture2D = D3D12_RTV_DIMENSION_TEXTURE2D.0,
+    Texture2DArray = D3D12_RTV_DIMENSION_TEXTURE2DARRAY.0,
+    Texture2DMS = D3D12_RTV_DIMENSION_TEXTURE2DMS.0,
+    Texture2DMSArray = D3D12_RTV_DIMENSION_TEXTURE2DMSARRAY.0,
+    Texture3D = D3D12_RTV_DIMENSION_TEXTURE3D.0,
+}
+
 /// Defines constants that specify sampler feedback support.
 ///
 /// For more information: [`D3D12_SAMPLER_FEEDBACK_TIER enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ne-d3d12-d3d12_sampler_feedback_tier)
@@ -3270,6 +3353,31 @@ pub enum ShaderModel {
     Model6_8 = D3D_SHADER_MODEL_6_8.0,
 }
 
+/// Specifies how memory gets routed by a shader resource view (SRV).
+///
+/// For more information: [`D3D12_SHADER_COMPONENT_MAPPING enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ne-d3d12-d3d12_shader_component_mapping)
+#[derive(Clone, Copy, Debug, FromRepr, Hash, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ShaderComponentMapping {
+    /// Indicates return component 0 (red).
+    FromMemoryComponent0 = D3D12_SHADER_COMPONENT_MAPPING_FROM_MEMORY_COMPONENT_0.0,
+
+    /// Indicates return component 1 (green).
+    FromMemoryComponent1 = D3D12_SHADER_COMPONENT_MAPPING_FROM_MEMORY_COMPONENT_1.0,
+
+    /// Indicates return component 2 (blue).
+    FromMemoryComponent2 = D3D12_SHADER_COMPONENT_MAPPING_FROM_MEMORY_COMPONENT_2.0,
+
+    /// Indicates return component 3 (alpha).
+    FromMemoryComponent3 = D3D12_SHADER_COMPONENT_MAPPING_FROM_MEMORY_COMPONENT_3.0,
+
+    /// Indicates forcing the resulting value to 0.
+    ForceValue0 = D3D12_SHADER_COMPONENT_MAPPING_FORCE_VALUE_0.0,
+
+    /// Indicates forcing the resulting value 1. The value of forcing 1 is either 0x1 or 1.0f depending on the format type for that component in the source format.
+    ForceValue1 = D3D12_SHADER_COMPONENT_MAPPING_FORCE_VALUE_1.0,
+}
+
 /// Values that identify resource types that can be bound to a shader and that are reflected as part of the resource description for the shader.
 ///
 /// For more information: [`D3D_SHADER_INPUT_TYPE enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3dcommon/ne-d3dcommon-d3d_shader_input_type)
@@ -3494,6 +3602,58 @@ pub enum ShaderVisibility {
     Mesh = D3D12_SHADER_VISIBILITY_MESH.0,
 }
 
+/// Defines constants that specify the shading rate (for variable-rate shading, or VRS).
+///
+/// For more information: [`D3D12_SHADING_RATE enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ne-d3d12-d3d12_shading_rate)
+#[derive(Clone, Copy, Debug, Default, FromRepr, Hash, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ShadingRate {
+    /// Specifies no change to the shading rate.
+    #[default]
+    Rate1x1 = D3D12_SHADING_RATE_1X1.0,
+
+    /// Specifies that the shading rate should reduce vertical resolution 2x.
+    Rate1x2 = D3D12_SHADING_RATE_1X2.0,
+
+    /// Specifies that the shading rate should reduce horizontal resolution 2x.
+    Rate2x1 = D3D12_SHADING_RATE_2X1.0,
+
+    /// Specifies that the shading rate should reduce the resolution of both axes 2x.
+    Rate2x2 = D3D12_SHADING_RATE_2X2.0,
+
+    /// Specifies that the shading rate should reduce horizontal resolution 2x, and reduce vertical resolution 4x.
+    Rate2x4 = D3D12_SHADING_RATE_2X4.0,
+
+    /// Specifies that the shading rate should reduce horizontal resolution 4x, and reduce vertical resolution 2x.
+    Rate4x2 = D3D12_SHADING_RATE_4X2.0,
+
+    /// Specifies that the shading rate should reduce the resolution of both axes 4x.
+    Rate4x4 = D3D12_SHADING_RATE_4X4.0,
+}
+
+/// Defines constants that specify shading rate combiners (for variable-rate shading, or VRS).
+///
+/// For more information: [`D3D12_SHADING_RATE_COMBINER enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ne-d3d12-d3d12_shading_rate_combiner)
+#[derive(Clone, Copy, Debug, Default, FromRepr, Hash, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ShadingRateCombiner {
+    /// Specifies the combiner C.xy = A.xy, for combiner (C) and inputs (A and B).
+    #[default]
+    Passthrough = D3D12_SHADING_RATE_COMBINER_PASSTHROUGH.0,
+
+    /// Specifies the combiner C.xy = B.xy, for combiner (C) and inputs (A and B).
+    Override = D3D12_SHADING_RATE_COMBINER_OVERRIDE.0,
+
+    /// Specifies the combiner C.xy = max(A.xy, B.xy), for combiner (C) and inputs (A and B).
+    Min = D3D12_SHADING_RATE_COMBINER_MIN.0,
+
+    /// Specifies the combiner C.xy = min(A.xy, B.xy), for combiner (C) and inputs (A and B).
+    Max = D3D12_SHADING_RATE_COMBINER_MAX.0,
+
+    /// Specifies the combiner C.xy = min(maxRate, A.xy + B.xy), for combiner (C) and inputs (A and B).
+    Sum = D3D12_SHADING_RATE_COMBINER_SUM.0,
+}
+
 /// Defines constants that specify a cross-API sharing support tier.
 ///
 /// For more information: [`D3D12_SHARED_RESOURCE_COMPATIBILITY_TIER enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ne-d3d12-d3d12_shared_resource_compatibility_tier)
@@ -3749,3 +3909,100 @@ pub enum WaveMmaTier {
     /// Specifies that WaveMMA (wave_matrix) operations are supported.
     Tier1_0 = D3D12_WAVE_MMA_TIER_1_0.0,
 }
+
+/// Specifies the mode used by a WriteBufferImmediate operation.
+///
+/// For more information: [`D3D12_WRITEBUFFERIMMEDIATE_MODE enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ne-d3d12-d3d12_writebufferimmediate_mode)
+#[derive(Clone, Copy, Debug, Default, FromRepr, Hash, PartialEq, Eq)]
+#[repr(i32)]
+pub enum WriteBufferImmediateMode {
+    /// The write operation behaves the same as normal copy-write operations.
+    #[default]
+    Default = D3D12_WRITEBUFFERIMMEDIATE_MODE_DEFAULT.0,
+
+    /// The write operation is guaranteed to occur after all preceding commands in the command stream have started, including previous WriteBufferImmediate operations.
+    MarkerIn = D3D12_WRITEBUFFERIMMEDIATE_MODE_MARKER_IN.0,
+
+    /// The write operation is deferred until all previous commands in the command stream have completed through the GPU pipeline, including previous WriteBufferImmediate operations.
+    MarkerOut = D3D12_WRITEBUFFERIMMEDIATE_MODE_MARKER_OUT.0,
+}
+
+#[cfg(test)]
+mod test {
+    use windows::Win32::Graphics::Dxgi::Common::DXGI_FORMAT;
+
+    use crate::dx::*;
+
+    #[test]
+    fn format_from_repr_test() {
+        let formats = (0..=Format::Abgr4Unorm as i32)
+            .filter_map(|raw| Format::from_repr(raw).map(|format| (raw, format)))
+            .inspect(|&(raw, format)| {
+                assert_eq!(format.as_raw(), DXGI_FORMAT(raw));
+                assert_eq!(Format::from(DXGI_FORMAT(raw)), format);
+            })
+            .map(|(_, format)| format)
+            .collect::<Vec<_>>();
+
+        for format in [
+            Format::Rgba16Float,
+            Format::Rgb10A2Unorm,
+            Format::Rg11B10Float,
+            Format::Rgb9E5,
+            Format::Bc1Unorm,
+            Format::Bc3UnormSrgb,
+            Format::Bc5Snorm,
+            Format::Bc6hUf16,
+            Format::Bc7Unorm,
+        ] {
+            assert!(formats.contains(&format));
+        }
+    }
+
+    #[test]
+    fn texture_layout_test() {
+        assert_eq!(TextureLayout::default(), TextureLayout::Unknown);
+
+        for layout in [
+            TextureLayout::Unknown,
+            TextureLayout::RowMajor,
+            TextureLayout::UndefinedSwizzle64Kb,
+            TextureLayout::StandardSwizzle64Kb,
+        ] {
+            assert_eq!(TextureLayout::from_repr(layout.as_raw().0), Some(layout));
+        }
+
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut options = features::OptionsFeature::default();
+        device.check_feature_support(&mut options).unwrap();
+
+        if !options.standard_swizzle_64kb_supported() {
+            return;
+        }
+
+        let texture = device.create_committed_resource(
+            &HeapProperties::default(),
+            HeapFlags::empty(),
+            &ResourceDesc::texture_2d(256, 256)
+                .with_format(Format::Rgba8Unorm)
+                .with_layout(TextureLayout::StandardSwizzle64Kb),
+            ResourceStates::Common,
+            None,
+        );
+
+        assert!(texture.is_ok());
+    }
+
+    #[test]
+    fn format_srgb_test() {
+        assert_eq!(Format::Rgba8Unorm.as_srgb(), Some(Format::Rgba8UnormSrgb));
+        assert_eq!(Format::Rgba8UnormSrgb.as_linear(), Format::Rgba8Unorm);
+        assert_eq!(
+            Format::Bc7Unorm.as_srgb().map(|f| f.as_linear()),
+            Some(Format::Bc7Unorm)
+        );
+        assert_eq!(Format::Rgba16Float.as_srgb(), None);
+        assert_eq!(Format::Rgba16Float.as_linear(), Format::Rgba16Float);
+    }
+}
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::{FeatureObject, __Sealed};
+use crate::{device::IDevice, error::DxError, FeatureObject, __Sealed};
 
 use super::*;
 
@@ -251,6 +251,12 @@ impl FormatInfoFeature {
         })
     }
 
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.0.Format.into()
+    }
+
+    /// See also [`IDevice::get_format_plane_count`](crate::device::IDevice::get_format_plane_count).
     #[inline]
     pub fn plane_count(&self) -> u8 {
         self.0.PlaneCount
@@ -298,6 +304,7 @@ impl FeatureObject for GpuVirtualAddressSupportFeature {
 pub struct ShaderModelFeature(pub(crate) D3D12_FEATURE_DATA_SHADER_MODEL);
 
 impl ShaderModelFeature {
+    /// `shader_model` is the highest model the application wants to use, the query fails if the runtime doesn't know it.
     #[inline]
     pub fn new(shader_model: ShaderModel) -> Self {
         Self(D3D12_FEATURE_DATA_SHADER_MODEL {
@@ -400,6 +407,14 @@ impl FeatureObject for ProtectedResourceSessionSupportFeature {
 pub struct RootSignatureFeature(pub(crate) D3D12_FEATURE_DATA_ROOT_SIGNATURE);
 
 impl RootSignatureFeature {
+    /// Queries support up to `highest_version`, the result is clamped to what the runtime supports.
+    #[inline]
+    pub fn new(highest_version: RootSignatureVersion) -> Self {
+        Self(D3D12_FEATURE_DATA_ROOT_SIGNATURE {
+            HighestVersion: highest_version.as_raw(),
+        })
+    }
+
     #[inline]
     pub fn highest_version(&self) -> RootSignatureVersion {
         self.0.HighestVersion.into()
@@ -438,6 +453,11 @@ impl Architecture1Feature {
         self.0.UMA.into()
     }
 
+    #[inline]
+    pub fn cache_coherent_uma(&self) -> bool {
+        self.0.CacheCoherentUMA.into()
+    }
+
     #[inline]
     pub fn isolated_mmu(&self) -> bool {
         self.0.IsolatedMMU.into()
@@ -687,6 +707,7 @@ impl Options5Feature {
         self.0.RenderPassesTier.into()
     }
 
+    /// DXR is available for any tier other than [`RaytracingTier::NotSupported`].
     #[inline]
     pub fn raytracing_tier(&self) -> RaytracingTier {
         self.0.RaytracingTier.into()
@@ -965,3 +986,382 @@ impl __Sealed for Options11Feature {}
 impl FeatureObject for Options11Feature {
     const TYPE: FeatureType = FeatureType::Options11;
 }
+
+/// The results of [`Options8Feature`], [`Options9Feature`], [`Options10Feature`] and [`Options11Feature`] queried together.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ExtendedOptions {
+    pub options8: Options8Feature,
+    pub options9: Options9Feature,
+    pub options10: Options10Feature,
+    pub options11: Options11Feature,
+}
+
+impl ExtendedOptions {
+    /// Runs the four queries on `device`, failing on the first one the runtime does not recognize.
+    pub fn query(device: &impl IDevice) -> Result<Self, DxError> {
+        let mut options = Self::default();
+
+        device.check_feature_support(&mut options.options8)?;
+        device.check_feature_support(&mut options.options9)?;
+        device.check_feature_support(&mut options.options10)?;
+        device.check_feature_support(&mut options.options11)?;
+
+        Ok(options)
+    }
+}
+
+/// Indicates whether or not enhanced barriers and relaxed format casting are supported.
+///
+/// For more information: [`D3D12_FEATURE_DATA_D3D12_OPTIONS12 structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_feature_data_d3d12_options12)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Options12Feature(pub(crate) D3D12_FEATURE_DATA_D3D12_OPTIONS12);
+
+impl Options12Feature {
+    #[inline]
+    pub fn enhanced_barriers_supported(&self) -> bool {
+        self.0.EnhancedBarriersSupported.into()
+    }
+
+    #[inline]
+    pub fn relaxed_format_casting_supported(&self) -> bool {
+        self.0.RelaxedFormatCastingSupported.into()
+    }
+}
+
+impl __Sealed for Options12Feature {}
+
+impl FeatureObject for Options12Feature {
+    const TYPE: FeatureType = FeatureType::Options12;
+}
+
+/// Indicates support for advanced texture operations, writable multisampled textures and independent front and back stencil references and masks.
+///
+/// For more information: [`D3D12_FEATURE_DATA_D3D12_OPTIONS14 structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_feature_data_d3d12_options14)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Options14Feature(pub(crate) D3D12_FEATURE_DATA_D3D12_OPTIONS14);
+
+impl Options14Feature {
+    #[inline]
+    pub fn advanced_texture_ops_supported(&self) -> bool {
+        self.0.AdvancedTextureOpsSupported.into()
+    }
+
+    #[inline]
+    pub fn writeable_msaa_textures_supported(&self) -> bool {
+        self.0.WriteableMSAATexturesSupported.into()
+    }
+
+    /// Whether the stencil reference and masks can differ between front and back faces.
+    #[inline]
+    pub fn independent_front_and_back_stencil_ref_mask_supported(&self) -> bool {
+        self.0.IndependentFrontAndBackStencilRefMaskSupported.into()
+    }
+}
+
+impl __Sealed for Options14Feature {}
+
+impl FeatureObject for Options14Feature {
+    const TYPE: FeatureType = FeatureType::Options14;
+}
+
+/// Indicates support for dynamic depth bias and GPU upload heaps.
+///
+/// For more information: [`D3D12_FEATURE_DATA_D3D12_OPTIONS16 structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_feature_data_d3d12_options16)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Options16Feature(pub(crate) D3D12_FEATURE_DATA_D3D12_OPTIONS16);
+
+impl Options16Feature {
+    #[inline]
+    pub fn dynamic_depth_bias_supported(&self) -> bool {
+        self.0.DynamicDepthBiasSupported.into()
+    }
+
+    /// Whether [`HeapType::GpuUpload`] can be used, which lets the CPU write directly into video memory on systems with resizable BAR.
+    #[inline]
+    pub fn gpu_upload_heap_supported(&self) -> bool {
+        self.0.GPUUploadHeapSupported.into()
+    }
+}
+
+impl __Sealed for Options16Feature {}
+
+impl FeatureObject for Options16Feature {
+    const TYPE: FeatureType = FeatureType::Options16;
+}
+
+/// Indicates whether the adapter supports predication, see [`IGraphicsCommandList::set_predication`](crate::command_list::IGraphicsCommandList::set_predication).
+///
+/// For more information: [`D3D12_FEATURE_DATA_PREDICATION structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_feature_data_predication)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct PredicationFeature(pub(crate) D3D12_FEATURE_DATA_PREDICATION);
+
+impl PredicationFeature {
+    #[inline]
+    pub fn supported(&self) -> bool {
+        self.0.Supported.into()
+    }
+}
+
+impl __Sealed for PredicationFeature {}
+
+impl FeatureObject for PredicationFeature {
+    const TYPE: FeatureType = FeatureType::Predication;
+}
+
+/// Indicates whether the adapter supports copies performed by dedicated copy hardware.
+///
+/// For more information: [`D3D12_FEATURE_DATA_HARDWARE_COPY structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_feature_data_hardware_copy)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct HardwareCopyFeature(pub(crate) D3D12_FEATURE_DATA_HARDWARE_COPY);
+
+impl HardwareCopyFeature {
+    #[inline]
+    pub fn supported(&self) -> bool {
+        self.0.Supported.into()
+    }
+}
+
+impl __Sealed for HardwareCopyFeature {}
+
+impl FeatureObject for HardwareCopyFeature {
+    const TYPE: FeatureType = FeatureType::HardwareCopy;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dx::{features::*, *};
+
+    #[test]
+    fn serialization_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = SerializationFeature::new(0);
+        device.check_feature_support(&mut feature).unwrap();
+
+        println!(
+            "heap serialization tier: {:?}",
+            feature.heap_serialization_tier()
+        );
+    }
+
+    #[test]
+    fn architecture1_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut architecture = ArchitectureFeature::new(0);
+        device.check_feature_support(&mut architecture).unwrap();
+
+        let mut architecture1 = Architecture1Feature::new(0);
+        device.check_feature_support(&mut architecture1).unwrap();
+
+        assert_eq!(architecture.uma(), architecture1.uma());
+    }
+
+    #[test]
+    fn options1_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = Options1Feature::default();
+        device.check_feature_support(&mut feature).unwrap();
+
+        if feature.wave_ops() {
+            assert!(feature.wave_lane_count_min() <= feature.wave_lane_count_max());
+        }
+    }
+
+    #[test]
+    fn options3_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = Options3Feature::default();
+        assert!(device.check_feature_support(&mut feature).is_ok());
+
+        println!("view instancing tier: {:?}", feature.view_instancing_tier());
+    }
+
+    #[test]
+    fn options2_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = Options2Feature::default();
+        assert!(device.check_feature_support(&mut feature).is_ok());
+    }
+
+    #[test]
+    fn hardware_copy_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = HardwareCopyFeature::default();
+        assert!(device.check_feature_support(&mut feature).is_ok());
+    }
+
+    #[test]
+    fn predication_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = PredicationFeature::default();
+        assert!(device.check_feature_support(&mut feature).is_ok());
+    }
+
+    #[test]
+    fn shader_cache_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = ShaderCacheFeature::default();
+        device.check_feature_support(&mut feature).unwrap();
+
+        let flags = feature.support_flags();
+        assert!(flags.contains(CacheSupportFlags::SinglePSO));
+
+        println!("shader cache support: {flags:?}");
+    }
+
+    #[test]
+    fn options4_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = Options4Feature::default();
+        device.check_feature_support(&mut feature).unwrap();
+
+        println!(
+            "shared resource compatibility tier: {:?}, msaa 64kb aligned textures: {}",
+            feature.shared_resource_compatibility_tier(),
+            feature.msaa_64kb_aligned_texture_supported()
+        );
+
+        if feature.native_16bit_shader_ops_supported() {
+            println!("min16float runs natively as 16-bit");
+        }
+    }
+
+    #[test]
+    fn gpu_upload_heap_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = Options16Feature::default();
+        if device.check_feature_support(&mut feature).is_err() {
+            return;
+        }
+
+        let Ok(heap_properties) = HeapProperties::gpu_upload_checked(&feature) else {
+            assert!(!feature.gpu_upload_heap_supported());
+            return;
+        };
+
+        let buffer = device
+            .create_committed_resource(
+                &heap_properties,
+                HeapFlags::empty(),
+                &ResourceDesc::buffer(256),
+                ResourceStates::Common,
+                None,
+            )
+            .unwrap();
+
+        let data = buffer.map::<u8>(0, None).unwrap();
+        unsafe { std::ptr::write_bytes(data.as_ptr(), 0xff, 256) };
+        buffer.unmap(0, None);
+    }
+
+    #[test]
+    fn options16_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = Options16Feature::default();
+        assert!(device.check_feature_support(&mut feature).is_ok());
+    }
+
+    #[test]
+    fn options14_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = Options14Feature::default();
+        assert!(device.check_feature_support(&mut feature).is_ok());
+    }
+
+    #[test]
+    fn feature_levels_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let levels = [
+            FeatureLevel::Level11,
+            FeatureLevel::Level11_1,
+            FeatureLevel::Level12,
+        ];
+        let mut feature = FeatureLevelsFeature::new(&levels);
+        device.check_feature_support(&mut feature).unwrap();
+
+        assert!(levels.contains(&feature.max_supported_feature_level()));
+    }
+
+    #[test]
+    fn options5_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = Options5Feature::default();
+        device.check_feature_support(&mut feature).unwrap();
+
+        if feature.raytracing_tier() == RaytracingTier::NotSupported {
+            return;
+        }
+
+        let mut shader_model = ShaderModelFeature::new(ShaderModel::Model6_3);
+        device.check_feature_support(&mut shader_model).unwrap();
+
+        assert_eq!(shader_model.highest_shader_model(), ShaderModel::Model6_3);
+    }
+
+    #[test]
+    fn shader_model_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut feature = ShaderModelFeature::new(ShaderModel::Model6_0);
+        device.check_feature_support(&mut feature).unwrap();
+
+        let supported = [ShaderModel::Model5_1, ShaderModel::Model6_0];
+        assert!(supported.contains(&feature.highest_shader_model()));
+    }
+
+    #[test]
+    fn format_info_feature_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let mut support = FormatSupportFeature::new(Format::Nv12);
+        if device.check_feature_support(&mut support).is_err()
+            || !support.support1().contains(FormatSupport1::Texture2D)
+        {
+            return;
+        }
+
+        let mut feature = FormatInfoFeature::new(Format::Nv12);
+        device.check_feature_support(&mut feature).unwrap();
+
+        assert_eq!(feature.format(), Format::Nv12);
+        assert_eq!(feature.plane_count(), 2);
+    }
+
+    #[test]
+    fn extended_options_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+
+        let options = ExtendedOptions::query(&device).unwrap();
+
+        let mut options8 = Options8Feature::default();
+        device.check_feature_support(&mut options8).unwrap();
+        assert_eq!(options.options8, options8);
+
+        let mut options11 = Options11Feature::default();
+        device.check_feature_support(&mut options11).unwrap();
+        assert_eq!(
+            options
+                .options11
+                .atomic_int64_on_descriptor_heap_resource_supported(),
+            options11.atomic_int64_on_descriptor_heap_resource_supported()
+        );
+    }
+}
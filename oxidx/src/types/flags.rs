@@ -71,13 +71,13 @@ bitflags::bitflags! {
         /// Indicates that the driver supports an OS-managed shader cache that stores compiled shaders on disk to accelerate future runs of the application.
         const AutomaticDiskCache = D3D12_SHADER_CACHE_SUPPORT_AUTOMATIC_DISK_CACHE.0;
 
-        /// TBD
+        /// Indicates that the driver maintains its own shader cache, separate from the OS-managed caches.
         const DriverManagedCache = D3D12_SHADER_CACHE_SUPPORT_DRIVER_MANAGED_CACHE.0;
 
-        /// TBD
+        /// Indicates that the driver-managed cache can be cleared with `ID3D12Device9::ShaderCacheControl`.
         const ShaderControlClear = D3D12_SHADER_CACHE_SUPPORT_SHADER_CONTROL_CLEAR.0;
 
-        /// TBD
+        /// Indicates that shader cache sessions can be deleted with `ID3D12ShaderCacheSession::SetDeleteOnDestroy`.
         const ShaderSessionDelete = D3D12_SHADER_CACHE_SUPPORT_SHADER_SESSION_DELETE.0;
     }
 }
@@ -251,7 +251,11 @@ bitflags::bitflags! {
 
 bitflags::bitflags! {
     /// Valid values include the `Debug` flag, and zero.
-    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    ///
+    /// Empty flag - Creates a release factory which doesn't depend on the debug layer being installed.
+    ///
+    /// For more information: [`CreateDXGIFactory2 function`](https://learn.microsoft.com/en-us/windows/win32/api/dxgi1_3/nf-dxgi1_3-createdxgifactory2)
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
     pub struct FactoryCreationFlags: u32 {
         /// Request DXGIDebug.dll; the DLL will be loaded if it is present on the system.
         const Debug = DXGI_CREATE_FACTORY_DEBUG.0;
@@ -646,6 +650,9 @@ bitflags::bitflags! {
         const AllowCrossAdapter = D3D12_RESOURCE_FLAG_ALLOW_CROSS_ADAPTER.0;
 
         /// Allows a resource to be simultaneously accessed by multiple different queues, devices, or processes.
+        ///
+        /// Only one queue may write to the resource at a time, while any number of them read regions which are not being written.
+        /// Can't be used with [`ResourceFlags::AllowDepthStencil`] or with multisampled textures, and buffers always behave this way.
         const AllowSimultaneousAccess = D3D12_RESOURCE_FLAG_ALLOW_SIMULTANEOUS_ACCESS.0;
 
         /// Specfies that this resource may be used only as a decode reference frame. It may be written to or read only by the video decode operation.
@@ -748,6 +755,31 @@ bitflags::bitflags! {
     }
 }
 
+impl ResourceStates {
+    const WRITE: Self = Self::RenderTarget
+        .union(Self::UnorderedAccess)
+        .union(Self::DepthWrite)
+        .union(Self::StreamOut)
+        .union(Self::CopyDest)
+        .union(Self::ResolveDest)
+        .union(Self::VideoDecodeWrite)
+        .union(Self::VideoProcessWrite)
+        .union(Self::VideoEncodeWrite);
+
+    /// Returns `false` for known-illegal mixes of states: at most one write state may be set,
+    /// and a write state can't be combined with any read state.
+    #[inline]
+    pub fn is_valid_combination(&self) -> bool {
+        let write = self.intersection(Self::WRITE);
+
+        match write.bits().count_ones() {
+            0 => true,
+            1 => write == *self,
+            _ => false,
+        }
+    }
+}
+
 bitflags::bitflags! {
     /// Specifies options for root signature layout.
     ///
@@ -798,6 +830,22 @@ bitflags::bitflags! {
     }
 }
 
+bitflags::bitflags! {
+    /// Specifies sampler options.
+    ///
+    /// Empty flag - No sampler flags are specified.
+    ///
+    /// For more information: [`D3D12_SAMPLER_FLAGS enumeration`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ne-d3d12-d3d12_sampler_flags)
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct SamplerFlags: i32 {
+        /// The border color is interpreted as unsigned integer values.
+        const UintBorderColor = D3D12_SAMPLER_FLAG_UINT_BORDER_COLOR.0;
+
+        /// Texture coordinates are not normalized.
+        const NonNormalizedCoordinates = D3D12_SAMPLER_FLAG_NON_NORMALIZED_COORDINATES.0;
+    }
+}
+
 bitflags::bitflags! {
     /// Specifies how to copy a tile.
     ///
@@ -1007,3 +1055,26 @@ bitflags::bitflags! {
         const NoPrintScreen = DXGI_MWA_NO_PRINT_SCREEN.0;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn resource_states_combination_test() {
+        let states = ResourceStates::PixelShaderResource | ResourceStates::NonPixelShaderResource;
+        assert!(states.is_valid_combination());
+        assert_eq!(states.as_raw(), ResourceStates::AllShaderResource.as_raw());
+
+        assert!(ResourceStates::GenericRead.is_valid_combination());
+        assert!(ResourceStates::Common.is_valid_combination());
+        assert!(ResourceStates::RenderTarget.is_valid_combination());
+
+        assert!(!(ResourceStates::RenderTarget | ResourceStates::CopyDest).is_valid_combination());
+        assert!(!(ResourceStates::DepthWrite | ResourceStates::DepthRead).is_valid_combination());
+        assert!(
+            !(ResourceStates::UnorderedAccess | ResourceStates::PixelShaderResource)
+                .is_valid_combination()
+        );
+    }
+}
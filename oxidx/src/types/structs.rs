@@ -78,6 +78,34 @@ impl AdapterDesc1 {
 pub struct BlendDesc(pub(crate) D3D12_BLEND_DESC);
 
 impl BlendDesc {
+    /// Every render target written as is, see [`RenderTargetBlendDesc::disabled`].
+    #[inline]
+    pub fn opaque() -> Self {
+        Self(D3D12_BLEND_DESC {
+            RenderTarget: [RenderTargetBlendDesc::disabled().0; 8],
+            ..Default::default()
+        })
+    }
+
+    /// Conventional transparency, `src * src_alpha + dst * (1 - src_alpha)`, keeping the alpha of the source.
+    #[inline]
+    pub fn alpha_blend() -> Self {
+        let desc = RenderTargetBlendDesc::blend_with_alpha(
+            Blend::SrcAlpha,
+            Blend::InvSrcAlpha,
+            BlendOp::Add,
+            Blend::One,
+            Blend::Zero,
+            BlendOp::Add,
+            ColorWriteEnable::all(),
+        );
+
+        Self(D3D12_BLEND_DESC {
+            RenderTarget: [desc.0; 8],
+            ..Default::default()
+        })
+    }
+
     #[inline]
     pub fn with_render_targets(
         mut self,
@@ -115,6 +143,99 @@ impl Default for BlendDesc {
     }
 }
 
+/// Describes the shader tables and the grid of a raytracing dispatch.
+///
+/// Shader records are made of a shader identifier returned by [`IStateObject::get_shader_identifier`](crate::pso::IStateObject::get_shader_identifier)
+/// followed by local root arguments, tables must be aligned to `D3D12_RAYTRACING_SHADER_TABLE_BYTE_ALIGNMENT`.
+///
+/// For more information: [`D3D12_DISPATCH_RAYS_DESC structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_dispatch_rays_desc)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DispatchRaysDesc(pub(crate) D3D12_DISPATCH_RAYS_DESC);
+
+impl DispatchRaysDesc {
+    /// Dispatches `width * height * depth` rays starting from the ray generation record at `ray_generation_record`.
+    #[inline]
+    pub fn new(
+        ray_generation_record: GpuVirtualAddress,
+        ray_generation_record_size: u64,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) -> Self {
+        Self(D3D12_DISPATCH_RAYS_DESC {
+            RayGenerationShaderRecord: D3D12_GPU_VIRTUAL_ADDRESS_RANGE {
+                StartAddress: ray_generation_record,
+                SizeInBytes: ray_generation_record_size,
+            },
+            Width: width,
+            Height: height,
+            Depth: depth,
+            ..Default::default()
+        })
+    }
+
+    #[inline]
+    pub fn with_miss_shader_table(
+        mut self,
+        start: GpuVirtualAddress,
+        size: u64,
+        stride: u64,
+    ) -> Self {
+        self.0.MissShaderTable = D3D12_GPU_VIRTUAL_ADDRESS_RANGE_AND_STRIDE {
+            StartAddress: start,
+            SizeInBytes: size,
+            StrideInBytes: stride,
+        };
+        self
+    }
+
+    #[inline]
+    pub fn with_hit_group_table(
+        mut self,
+        start: GpuVirtualAddress,
+        size: u64,
+        stride: u64,
+    ) -> Self {
+        self.0.HitGroupTable = D3D12_GPU_VIRTUAL_ADDRESS_RANGE_AND_STRIDE {
+            StartAddress: start,
+            SizeInBytes: size,
+            StrideInBytes: stride,
+        };
+        self
+    }
+
+    #[inline]
+    pub fn with_callable_shader_table(
+        mut self,
+        start: GpuVirtualAddress,
+        size: u64,
+        stride: u64,
+    ) -> Self {
+        self.0.CallableShaderTable = D3D12_GPU_VIRTUAL_ADDRESS_RANGE_AND_STRIDE {
+            StartAddress: start,
+            SizeInBytes: size,
+            StrideInBytes: stride,
+        };
+        self
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.0.Width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.0.Height
+    }
+
+    #[inline]
+    pub fn depth(&self) -> u32 {
+        self.0.Depth
+    }
+}
+
 /// Describes a 3D box.
 ///
 /// For more information: [`D3D12_BOX structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_box)
@@ -168,6 +289,7 @@ impl DxBox {
 pub struct ClearValue(pub(crate) D3D12_CLEAR_VALUE);
 
 impl ClearValue {
+    /// Clear value for a render target, `value` is RGBA.
     #[inline]
     pub fn color(format: Format, value: impl Into<[f32; 4]>) -> Self {
         Self(D3D12_CLEAR_VALUE {
@@ -178,6 +300,7 @@ impl ClearValue {
         })
     }
 
+    /// Clear value for a depth-stencil buffer.
     #[inline]
     pub fn depth(format: Format, depth: f32, stencil: u8) -> Self {
         Self(D3D12_CLEAR_VALUE {
@@ -338,10 +461,10 @@ pub struct ComputePipelineStateDesc<'a>(
 
 impl<'a> ComputePipelineStateDesc<'a> {
     #[inline]
-    pub fn new(cs: &'a Blob) -> Self {
+    pub fn new(cs: impl Into<ShaderBytecode<'a>>) -> Self {
         Self(
             D3D12_COMPUTE_PIPELINE_STATE_DESC {
-                CS: cs.as_shader_bytecode(),
+                CS: cs.into().as_raw(),
                 ..Default::default()
             },
             Default::default(),
@@ -356,12 +479,21 @@ impl<'a> ComputePipelineStateDesc<'a> {
         }
     }
 
+    /// Blob returned by [`IPipelineState::get_cached_blob`](crate::pso::IPipelineState::get_cached_blob) on an earlier run.
+    ///
+    /// Without it the cached blob pointer stays null and its size zero, so the driver compiles the shader from scratch.
     #[inline]
     pub fn with_cache(mut self, cache: &'a Blob) -> Self {
         self.0.CachedPSO = cache.as_cached_pipeline_state();
         self
     }
 
+    #[inline]
+    pub fn with_node_mask(mut self, node_mask: u32) -> Self {
+        self.0.NodeMask = node_mask;
+        self
+    }
+
     #[inline]
     pub fn with_flags(mut self, flags: PipelineStateFlags) -> Self {
         self.0.Flags = flags.as_raw();
@@ -388,6 +520,9 @@ impl ConstantBufferViewDesc {
 
 /// Describes a CPU descriptor handle.
 ///
+/// Handles are obtained from [`IDescriptorHeap::get_cpu_descriptor_handle_for_heap_start`](crate::descriptor_heap::IDescriptorHeap::get_cpu_descriptor_handle_for_heap_start)
+/// and advanced by the size returned from [`IDevice::get_descriptor_handle_increment_size`](crate::device::IDevice::get_descriptor_handle_increment_size).
+///
 /// For more information: [`D3D12_CPU_DESCRIPTOR_HANDLE structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_cpu_descriptor_handle)
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 #[repr(transparent)]
@@ -619,6 +754,29 @@ impl DepthStencilViewDesc {
             Flags: D3D12_DSV_FLAG_NONE,
         })
     }
+
+    /// Read-only views let the same texture be bound for depth testing while shaders sample it through a shader resource view,
+    /// with the resource in [`ResourceStates::DepthRead`] combined with a shader resource state.
+    #[inline]
+    pub fn with_flags(mut self, flags: DsvFlags) -> Self {
+        self.0.Flags = flags.as_raw();
+        self
+    }
+
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.0.Format.into()
+    }
+
+    #[inline]
+    pub fn dimension(&self) -> DsvDimension {
+        self.0.ViewDimension.into()
+    }
+
+    #[inline]
+    pub fn flags(&self) -> DsvFlags {
+        self.0.Flags.into()
+    }
 }
 
 /// Describes the descriptor heap.
@@ -803,8 +961,30 @@ impl<'a> DiscardRegion<'a> {
     }
 }
 
+/// Describes a DXIL library state subobject, all shaders of the library are exported.
+///
+/// For more information: [`D3D12_DXIL_LIBRARY_DESC structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_dxil_library_desc)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DxilLibraryDesc<'a>(pub(crate) D3D12_DXIL_LIBRARY_DESC, PhantomData<&'a ()>);
+
+impl<'a> DxilLibraryDesc<'a> {
+    #[inline]
+    pub fn new(library: impl Into<ShaderBytecode<'a>>) -> Self {
+        Self(
+            D3D12_DXIL_LIBRARY_DESC {
+                DXILLibrary: library.into().as_raw(),
+                ..Default::default()
+            },
+            Default::default(),
+        )
+    }
+}
+
 /// Describes a GPU descriptor handle.
 ///
+/// Only shader-visible heaps have a GPU start handle, see [`IDescriptorHeap::get_gpu_descriptor_handle_for_heap_start`](crate::descriptor_heap::IDescriptorHeap::get_gpu_descriptor_handle_for_heap_start).
+///
 /// For more information: [`D3D12_GPU_DESCRIPTOR_HANDLE structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_gpu_descriptor_handle)
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(transparent)]
@@ -825,6 +1005,27 @@ impl GpuDescriptorHandle {
     }
 }
 
+/// Defines the global root signature state subobject of a state object.
+///
+/// For more information: [`D3D12_GLOBAL_ROOT_SIGNATURE structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_global_root_signature)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct GlobalRootSignature<'a>(pub(crate) D3D12_GLOBAL_ROOT_SIGNATURE, PhantomData<&'a ()>);
+
+impl<'a> GlobalRootSignature<'a> {
+    #[inline]
+    pub fn new(root_signature: &'a RootSignature) -> Self {
+        unsafe {
+            Self(
+                D3D12_GLOBAL_ROOT_SIGNATURE {
+                    pGlobalRootSignature: std::mem::transmute_copy(root_signature.as_raw()),
+                },
+                Default::default(),
+            )
+        }
+    }
+}
+
 /// Describes a graphics pipeline state object.
 ///
 /// For more information: [`D3D12_GRAPHICS_PIPELINE_STATE_DESC structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_graphics_pipeline_state_desc)
@@ -837,10 +1038,10 @@ pub struct GraphicsPipelineDesc<'a>(
 
 impl<'a> GraphicsPipelineDesc<'a> {
     #[inline]
-    pub fn new(vs: &'a Blob) -> Self {
+    pub fn new(vs: impl Into<ShaderBytecode<'a>>) -> Self {
         Self(
             D3D12_GRAPHICS_PIPELINE_STATE_DESC {
-                VS: vs.as_shader_bytecode(),
+                VS: vs.into().as_raw(),
                 SampleDesc: DXGI_SAMPLE_DESC {
                     Count: 1,
                     Quality: 0,
@@ -862,26 +1063,26 @@ impl<'a> GraphicsPipelineDesc<'a> {
     }
 
     #[inline]
-    pub fn with_ps(mut self, ps: &'a Blob) -> Self {
-        self.0.PS = ps.as_shader_bytecode();
+    pub fn with_ps(mut self, ps: impl Into<ShaderBytecode<'a>>) -> Self {
+        self.0.PS = ps.into().as_raw();
         self
     }
 
     #[inline]
-    pub fn with_ds(mut self, ds: &'a Blob) -> Self {
-        self.0.DS = ds.as_shader_bytecode();
+    pub fn with_ds(mut self, ds: impl Into<ShaderBytecode<'a>>) -> Self {
+        self.0.DS = ds.into().as_raw();
         self
     }
 
     #[inline]
-    pub fn with_hs(mut self, hs: &'a Blob) -> Self {
-        self.0.HS = hs.as_shader_bytecode();
+    pub fn with_hs(mut self, hs: impl Into<ShaderBytecode<'a>>) -> Self {
+        self.0.HS = hs.into().as_raw();
         self
     }
 
     #[inline]
-    pub fn with_gs(mut self, gs: &'a Blob) -> Self {
-        self.0.GS = gs.as_shader_bytecode();
+    pub fn with_gs(mut self, gs: impl Into<ShaderBytecode<'a>>) -> Self {
+        self.0.GS = gs.into().as_raw();
         self
     }
 
@@ -997,6 +1198,18 @@ impl HeapDesc {
         })
     }
 
+    /// Default heap which can be shared across adapters. Only buffers and textures
+    /// with [`TextureLayout::RowMajor`] and [`ResourceFlags::AllowCrossAdapter`] can be placed into it.
+    #[inline]
+    pub fn shared_cross_adapter(size: usize) -> Self {
+        Self(D3D12_HEAP_DESC {
+            SizeInBytes: size as u64,
+            Properties: HeapProperties::default().0,
+            Flags: D3D12_HEAP_FLAG_SHARED | D3D12_HEAP_FLAG_SHARED_CROSS_ADAPTER,
+            ..Default::default()
+        })
+    }
+
     #[inline]
     pub fn with_alignment(mut self, alignment: HeapAlignment) -> Self {
         self.0.Alignment = alignment.as_raw();
@@ -1076,6 +1289,10 @@ impl HeapProperties {
         })
     }
 
+    /// CPU-visible video memory.
+    ///
+    /// Query [`Options16Feature::gpu_upload_heap_supported`](crate::types::features::Options16Feature::gpu_upload_heap_supported)
+    /// before using it, creating resources in this heap fails on adapters without resizable BAR.
     #[inline]
     pub fn gpu_upload() -> Self {
         Self(D3D12_HEAP_PROPERTIES {
@@ -1084,6 +1301,16 @@ impl HeapProperties {
         })
     }
 
+    /// Same as [`HeapProperties::gpu_upload`], returning [`DxError::NotImpl`] if the device `options` were queried from lacks GPU upload heaps.
+    #[inline]
+    pub fn gpu_upload_checked(options: &features::Options16Feature) -> Result<Self, DxError> {
+        if !options.gpu_upload_heap_supported() {
+            return Err(DxError::NotImpl);
+        }
+
+        Ok(Self::gpu_upload())
+    }
+
     #[inline]
     pub fn with_cpu_page_property(mut self, cpu_page_property: CpuPageProperty) -> Self {
         self.0.CPUPageProperty = cpu_page_property.as_raw();
@@ -1108,6 +1335,15 @@ impl HeapProperties {
         self
     }
 
+    /// Places the heap on the nodes in `creation_mask` and makes it visible to the nodes in `visible_mask`.
+    ///
+    /// On single-adapter systems both masks are `0` or `1`; `creation_mask` must have exactly one bit set and be a subset of `visible_mask`.
+    #[inline]
+    pub fn on_node(self, creation_mask: u32, visible_mask: u32) -> Self {
+        self.with_creation_node_mask(creation_mask)
+            .with_visible_node_mask(visible_mask)
+    }
+
     #[inline]
     pub fn r#type(&self) -> HeapType {
         self.0.Type.into()
@@ -1162,6 +1398,36 @@ impl IndexBufferView {
             Format: format.as_raw(),
         })
     }
+
+    /// Creates a view of `count` indices, the size in bytes is derived from the stride of `format`.
+    ///
+    /// # Panics
+    /// If `format` is neither [`Format::R16Uint`] nor [`Format::R32Uint`].
+    #[inline]
+    pub fn from_count(buffer_location: GpuVirtualAddress, count: usize, format: Format) -> Self {
+        let stride = match format {
+            Format::R16Uint => 2,
+            Format::R32Uint => 4,
+            _ => panic!("index format must be R16Uint or R32Uint, got {format:?}"),
+        };
+
+        Self::new(buffer_location, count * stride, format)
+    }
+
+    #[inline]
+    pub fn buffer_location(&self) -> GpuVirtualAddress {
+        self.0.BufferLocation
+    }
+
+    #[inline]
+    pub fn size_in_bytes(&self) -> u32 {
+        self.0.SizeInBytes
+    }
+
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.0.Format.into()
+    }
 }
 
 /// Specifies the type of the indirect parameter.
@@ -1358,6 +1624,39 @@ impl Luid {
     }
 }
 
+/// Describes the dimensions of a mip region.
+///
+/// For more information: [`D3D12_MIP_REGION structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_mip_region)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct MipRegion(pub(crate) D3D12_MIP_REGION);
+
+impl MipRegion {
+    #[inline]
+    pub fn new(width: u32, height: u32, depth: u32) -> Self {
+        Self(D3D12_MIP_REGION {
+            Width: width,
+            Height: height,
+            Depth: depth,
+        })
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.0.Width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.0.Height
+    }
+
+    #[inline]
+    pub fn depth(&self) -> u32 {
+        self.0.Depth
+    }
+}
+
 /// Describes a display mode and whether the display mode supports stereo.
 ///
 /// For more information: [`DXGI_MODE_DESC1 structure`](https://learn.microsoft.com/en-us/windows/win32/api/dxgi1_2/ns-dxgi1_2-dxgi_mode_desc1)
@@ -1718,6 +2017,39 @@ impl Rational {
     }
 }
 
+/// Represents the maximum recursion depth of a raytracing pipeline.
+///
+/// For more information: [`D3D12_RAYTRACING_PIPELINE_CONFIG structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_raytracing_pipeline_config)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct RaytracingPipelineConfig(pub(crate) D3D12_RAYTRACING_PIPELINE_CONFIG);
+
+impl RaytracingPipelineConfig {
+    #[inline]
+    pub fn new(max_trace_recursion_depth: u32) -> Self {
+        Self(D3D12_RAYTRACING_PIPELINE_CONFIG {
+            MaxTraceRecursionDepth: max_trace_recursion_depth,
+        })
+    }
+}
+
+/// Represents the maximum sizes of the ray payload and intersection attributes of a raytracing pipeline.
+///
+/// For more information: [`D3D12_RAYTRACING_SHADER_CONFIG structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_raytracing_shader_config)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct RaytracingShaderConfig(pub(crate) D3D12_RAYTRACING_SHADER_CONFIG);
+
+impl RaytracingShaderConfig {
+    #[inline]
+    pub fn new(max_payload_size: u32, max_attribute_size: u32) -> Self {
+        Self(D3D12_RAYTRACING_SHADER_CONFIG {
+            MaxPayloadSizeInBytes: max_payload_size,
+            MaxAttributeSizeInBytes: max_attribute_size,
+        })
+    }
+}
+
 /// The RECT structure defines a rectangle by the coordinates of its upper-left and lower-right corners.
 ///
 /// For more information: [`RECT structure`](https://learn.microsoft.com/en-us/windows/win32/api/windef/ns-windef-rect)
@@ -1754,6 +2086,23 @@ impl Rect {
 pub struct RenderTargetBlendDesc(pub(crate) D3D12_RENDER_TARGET_BLEND_DESC);
 
 impl RenderTargetBlendDesc {
+    /// Blending and logic operations off, all channels written and the blend factors set to their API defaults.
+    #[inline]
+    pub fn disabled() -> Self {
+        Self(D3D12_RENDER_TARGET_BLEND_DESC {
+            BlendEnable: false.into(),
+            LogicOpEnable: false.into(),
+            SrcBlend: D3D12_BLEND_ONE,
+            DestBlend: D3D12_BLEND_ZERO,
+            BlendOp: D3D12_BLEND_OP_ADD,
+            SrcBlendAlpha: D3D12_BLEND_ONE,
+            DestBlendAlpha: D3D12_BLEND_ZERO,
+            BlendOpAlpha: D3D12_BLEND_OP_ADD,
+            LogicOp: D3D12_LOGIC_OP_NOOP,
+            RenderTargetWriteMask: ColorWriteEnable::all().bits() as u8,
+        })
+    }
+
     #[inline]
     pub fn blend(
         src_blend: Blend,
@@ -1944,6 +2293,45 @@ impl RenderTargetViewDesc {
             },
         })
     }
+
+    /// Builds a view of mip 0 covering every array slice of a texture, inferring the dimension from its description.
+    ///
+    /// Returns `None` for buffers, since their element count depends on the view format.
+    #[inline]
+    pub fn from_resource(desc: &ResourceDesc) -> Option<Self> {
+        let format = desc.format();
+        let array_size = desc.depth_or_array_size() as u32;
+        let multisampled = desc.0.SampleDesc.Count > 1;
+
+        let view = match desc.dimension() {
+            ResourceDimension::Texture1D if array_size > 1 => {
+                Self::texture_1d_array(format, 0, 0..array_size)
+            }
+            ResourceDimension::Texture1D => Self::texture_1d(format, 0),
+            ResourceDimension::Texture2D if multisampled && array_size > 1 => {
+                Self::texture_2d_ms_array(format, 0..array_size)
+            }
+            ResourceDimension::Texture2D if multisampled => Self::texture_2d_ms(format),
+            ResourceDimension::Texture2D if array_size > 1 => {
+                Self::texture_2d_array(format, 0, 0, 0..array_size)
+            }
+            ResourceDimension::Texture2D => Self::texture_2d(format, 0, 0),
+            ResourceDimension::Texture3D => Self::texture_3d(format, 0, 0..array_size),
+            ResourceDimension::Buffer | ResourceDimension::Unknown => return None,
+        };
+
+        Some(view)
+    }
+
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.0.Format.into()
+    }
+
+    #[inline]
+    pub fn dimension(&self) -> RtvDimension {
+        self.0.ViewDimension.into()
+    }
 }
 
 /// Describes parameters needed to allocate resources.
@@ -1965,8 +2353,35 @@ impl ResourceAllocationInfo {
     }
 }
 
+/// Describes the placement of one resource within a collection, see [`IDevice::get_resource_allocation_info3`](crate::device::IDevice::get_resource_allocation_info3).
+///
+/// For more information: [`D3D12_RESOURCE_ALLOCATION_INFO1 structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_resource_allocation_info1)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ResourceAllocationInfo1(pub(crate) D3D12_RESOURCE_ALLOCATION_INFO1);
+
+impl ResourceAllocationInfo1 {
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.0.Offset
+    }
+
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.0.SizeInBytes
+    }
+
+    #[inline]
+    pub fn alignment(&self) -> u64 {
+        self.0.Alignment
+    }
+}
+
 /// Describes a resource barrier (transition in resource use).
 ///
+/// The barrier borrows its resources: the pointers inside the union are bit copies which are never `AddRef`'d,
+/// and the union members are `ManuallyDrop`, so neither building, cloning nor dropping a barrier touches the reference count.
+///
 /// For more information: [`D3D12_RESOURCE_BARRIER structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_resource_barrier)
 #[derive(Clone)]
 #[repr(transparent)]
@@ -1980,6 +2395,9 @@ impl<'a> ResourceBarrier<'a> {
         after: ResourceStates,
         subresource: Option<u32>,
     ) -> Self {
+        debug_assert!(before.is_valid_combination(), "invalid state: {before:?}");
+        debug_assert!(after.is_valid_combination(), "invalid state: {after:?}");
+
         Self(
             D3D12_RESOURCE_BARRIER {
                 Type: D3D12_RESOURCE_BARRIER_TYPE_TRANSITION,
@@ -1997,6 +2415,10 @@ impl<'a> ResourceBarrier<'a> {
         )
     }
 
+    /// Marks the point where `after` starts to use the heap memory it shares with `before`.
+    ///
+    /// Any work touching `before` must be recorded ahead of the barrier and work on `after` behind it,
+    /// afterwards the contents of `after` are undefined until it is fully written, e.g. cleared or copied into.
     #[inline]
     pub fn aliasing(before: &'a Resource, after: &'a Resource) -> Self {
         Self(
@@ -2014,6 +2436,26 @@ impl<'a> ResourceBarrier<'a> {
         )
     }
 
+    /// Aliasing barrier between any placed or reserved resources, use it when the resources involved are not known.
+    ///
+    /// It can be considerably more expensive than [`ResourceBarrier::aliasing`] since the GPU has to flush all outstanding work.
+    #[inline]
+    pub fn aliasing_any() -> Self {
+        Self(
+            D3D12_RESOURCE_BARRIER {
+                Type: D3D12_RESOURCE_BARRIER_TYPE_ALIASING,
+                Flags: D3D12_RESOURCE_BARRIER_FLAG_NONE,
+                Anonymous: D3D12_RESOURCE_BARRIER_0 {
+                    Aliasing: ManuallyDrop::new(D3D12_RESOURCE_ALIASING_BARRIER {
+                        pResourceBefore: ManuallyDrop::new(None),
+                        pResourceAfter: ManuallyDrop::new(None),
+                    }),
+                },
+            },
+            Default::default(),
+        )
+    }
+
     #[inline]
     pub fn uav(resource: &'a Resource) -> Self {
         Self(
@@ -2045,6 +2487,7 @@ impl<'a> ResourceBarrier<'a> {
 pub struct ResourceDesc(pub(crate) D3D12_RESOURCE_DESC);
 
 impl ResourceDesc {
+    /// A row-major buffer of `size` bytes with [`Format::Unknown`].
     #[inline]
     pub fn buffer(size: usize) -> Self {
         Self(D3D12_RESOURCE_DESC {
@@ -2080,6 +2523,9 @@ impl ResourceDesc {
         })
     }
 
+    /// A single 2D texture with one mip level and no multisampling.
+    ///
+    /// The format is left [`Format::Unknown`], set it with [`ResourceDesc::with_format`].
     #[inline]
     pub fn texture_2d(width: u32, height: u32) -> Self {
         Self(D3D12_RESOURCE_DESC {
@@ -2112,6 +2558,15 @@ impl ResourceDesc {
         })
     }
 
+    /// A 2D texture array of six square faces, to be viewed with [`ShaderResourceViewDesc::texture_cube`].
+    #[inline]
+    pub fn texture_cube(format: Format, size: u32, mip_levels: u32) -> Self {
+        Self::texture_2d(size, size)
+            .with_format(format)
+            .with_array_size(6)
+            .with_mip_levels(mip_levels)
+    }
+
     #[inline]
     pub fn with_alignment(mut self, alignment: HeapAlignment) -> Self {
         self.0.Alignment = alignment.as_raw();
@@ -2189,6 +2644,25 @@ impl ResourceDesc {
         self.0.MipLevels as u32
     }
 
+    /// Number of mips in a full chain for the width, height and, for 3D textures, depth of the resource.
+    ///
+    /// Buffers always have a single level.
+    #[inline]
+    pub fn max_mip_levels(&self) -> u16 {
+        if self.0.Dimension == D3D12_RESOURCE_DIMENSION_BUFFER {
+            return 1;
+        }
+
+        let depth = if self.0.Dimension == D3D12_RESOURCE_DIMENSION_TEXTURE3D {
+            self.0.DepthOrArraySize as u64
+        } else {
+            1
+        };
+        let size = self.0.Width.max(self.0.Height as u64).max(depth);
+
+        (u64::BITS - size.leading_zeros()) as u16
+    }
+
     #[inline]
     pub fn sample_desc(&self) -> SampleDesc {
         SampleDesc(self.0.SampleDesc)
@@ -2203,6 +2677,115 @@ impl ResourceDesc {
     pub fn flags(&self) -> ResourceFlags {
         self.0.Flags.into()
     }
+
+    /// Rejects descriptions which D3D12 either accepts silently with unusable results or refuses with a cryptic error:
+    /// * zero `width` of buffers and zero `width`/`height` of textures;
+    /// * more `mip_levels` than [`ResourceDesc::max_mip_levels`];
+    /// * [`ResourceFlags::AllowDepthStencil`] combined with [`ResourceFlags::AllowUnorderedAccess`] or [`ResourceFlags::AllowRenderTarget`];
+    /// * [`ResourceFlags::AllowSimultaneousAccess`] on depth-stencil or multisampled textures.
+    #[inline]
+    pub(crate) fn validate(&self) -> Result<(), DxError> {
+        if self.0.Width == 0 {
+            return Err(DxError::InvalidArgsMsg("resource width is zero"));
+        }
+
+        if self.0.Height == 0 {
+            return Err(DxError::InvalidArgsMsg("resource height is zero"));
+        }
+
+        // Zero requests the full chain
+        if self.0.MipLevels > self.max_mip_levels() {
+            return Err(DxError::InvalidArgsMsg(
+                "mip_levels exceeds the full mip chain",
+            ));
+        }
+
+        let flags = self.flags();
+
+        if flags.contains(ResourceFlags::AllowDepthStencil | ResourceFlags::AllowUnorderedAccess) {
+            return Err(DxError::InvalidArgsMsg(
+                "AllowDepthStencil can't be combined with AllowUnorderedAccess",
+            ));
+        }
+
+        if flags.contains(ResourceFlags::AllowDepthStencil | ResourceFlags::AllowRenderTarget) {
+            return Err(DxError::InvalidArgsMsg(
+                "AllowDepthStencil can't be combined with AllowRenderTarget",
+            ));
+        }
+
+        if flags.contains(ResourceFlags::AllowSimultaneousAccess | ResourceFlags::AllowDepthStencil)
+        {
+            return Err(DxError::InvalidArgsMsg(
+                "AllowSimultaneousAccess can't be combined with AllowDepthStencil",
+            ));
+        }
+
+        if flags.contains(ResourceFlags::AllowSimultaneousAccess) && self.0.SampleDesc.Count > 1 {
+            return Err(DxError::InvalidArgsMsg(
+                "AllowSimultaneousAccess requires a sample count of 1",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Describes a resource, such as a texture, including the mip region of its sampler feedback map.
+///
+/// For more information: [`D3D12_RESOURCE_DESC1 structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_resource_desc1)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ResourceDesc1(pub(crate) D3D12_RESOURCE_DESC1);
+
+impl ResourceDesc1 {
+    /// Only meaningful for sampler feedback formats, where it gives the texel area of the paired texture covered by one feedback texel.
+    #[inline]
+    pub fn with_sampler_feedback_mip_region(mut self, mip_region: MipRegion) -> Self {
+        self.0.SamplerFeedbackMipRegion = mip_region.0;
+        self
+    }
+
+    #[inline]
+    pub fn sampler_feedback_mip_region(&self) -> MipRegion {
+        MipRegion(self.0.SamplerFeedbackMipRegion)
+    }
+
+    /// Description without the sampler feedback mip region.
+    #[inline]
+    pub fn base(&self) -> ResourceDesc {
+        ResourceDesc(D3D12_RESOURCE_DESC {
+            Dimension: self.0.Dimension,
+            Alignment: self.0.Alignment,
+            Width: self.0.Width,
+            Height: self.0.Height,
+            DepthOrArraySize: self.0.DepthOrArraySize,
+            MipLevels: self.0.MipLevels,
+            Format: self.0.Format,
+            SampleDesc: self.0.SampleDesc,
+            Layout: self.0.Layout,
+            Flags: self.0.Flags,
+        })
+    }
+}
+
+impl From<ResourceDesc> for ResourceDesc1 {
+    #[inline]
+    fn from(value: ResourceDesc) -> Self {
+        Self(D3D12_RESOURCE_DESC1 {
+            Dimension: value.0.Dimension,
+            Alignment: value.0.Alignment,
+            Width: value.0.Width,
+            Height: value.0.Height,
+            DepthOrArraySize: value.0.DepthOrArraySize,
+            MipLevels: value.0.MipLevels,
+            Format: value.0.Format,
+            SampleDesc: value.0.SampleDesc,
+            Layout: value.0.Layout,
+            Flags: value.0.Flags,
+            SamplerFeedbackMipRegion: D3D12_MIP_REGION::default(),
+        })
+    }
 }
 
 /// Describes the slot of a root signature version 1.0.
@@ -2230,6 +2813,12 @@ impl<'a> RootParameter<'a> {
         )
     }
 
+    /// Shorthand for [`RootParameter::descriptor_table`] with a table made of the single range `range`.
+    #[inline]
+    pub fn descriptor_table_single(range: &'a DescriptorRange) -> Self {
+        Self::descriptor_table(std::slice::from_ref(range))
+    }
+
     #[inline]
     pub fn constant_32bit(
         shader_register: u32,
@@ -2452,6 +3041,157 @@ impl SamplerDesc {
     }
 }
 
+/// Describes a sampler state with sampler flags and either a float or an unsigned integer border color.
+///
+/// For more information: [`D3D12_SAMPLER_DESC2 structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_sampler_desc2)
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct SamplerDesc2(pub(crate) D3D12_SAMPLER_DESC2);
+
+impl SamplerDesc2 {
+    #[inline]
+    pub fn new(filter: Filter) -> Self {
+        Self::default().with_filter(filter)
+    }
+
+    #[inline]
+    pub fn point() -> Self {
+        Self::default().with_filter(Filter::Point)
+    }
+
+    #[inline]
+    pub fn linear() -> Self {
+        Self::default().with_filter(Filter::Linear)
+    }
+
+    #[inline]
+    pub fn anisotropic() -> Self {
+        Self::default().with_filter(Filter::Anisotropic)
+    }
+
+    #[inline]
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.0.Filter = filter.as_raw();
+        self
+    }
+
+    #[inline]
+    pub fn with_address_u(mut self, address: AddressMode) -> Self {
+        self.0.AddressU = address.as_raw();
+        self
+    }
+
+    #[inline]
+    pub fn with_address_v(mut self, address: AddressMode) -> Self {
+        self.0.AddressV = address.as_raw();
+        self
+    }
+
+    #[inline]
+    pub fn with_address_w(mut self, address: AddressMode) -> Self {
+        self.0.AddressW = address.as_raw();
+        self
+    }
+
+    #[inline]
+    pub fn with_mip_lod_bias(mut self, mip_lod_bias: f32) -> Self {
+        self.0.MipLODBias = mip_lod_bias;
+        self
+    }
+
+    #[inline]
+    pub fn with_max_anisotropy(mut self, max_anisotropy: u32) -> Self {
+        self.0.MaxAnisotropy = max_anisotropy;
+        self
+    }
+
+    #[inline]
+    pub fn with_comparison_func(mut self, comparison_func: ComparisonFunc) -> Self {
+        self.0.ComparisonFunc = comparison_func.as_raw();
+        self
+    }
+
+    /// Sets a float border color and clears [`SamplerFlags::UintBorderColor`].
+    #[inline]
+    pub fn with_border_color(mut self, border_color: impl Into<[f32; 4]>) -> Self {
+        self.0.Anonymous.FloatBorderColor = border_color.into();
+        self.0.Flags &= !D3D12_SAMPLER_FLAG_UINT_BORDER_COLOR;
+        self
+    }
+
+    /// Sets an unsigned integer border color and sets [`SamplerFlags::UintBorderColor`].
+    #[inline]
+    pub fn with_uint_border_color(mut self, border_color: impl Into<[u32; 4]>) -> Self {
+        self.0.Anonymous.UintBorderColor = border_color.into();
+        self.0.Flags |= D3D12_SAMPLER_FLAG_UINT_BORDER_COLOR;
+        self
+    }
+
+    #[inline]
+    pub fn with_lod(mut self, lod: Range<f32>) -> Self {
+        self.0.MinLOD = lod.start;
+        self.0.MaxLOD = lod.end;
+        self
+    }
+
+    #[inline]
+    pub fn with_flags(mut self, flags: SamplerFlags) -> Self {
+        self.0.Flags = flags.as_raw();
+        self
+    }
+
+    #[inline]
+    pub fn flags(&self) -> SamplerFlags {
+        self.0.Flags.into()
+    }
+}
+
+impl From<SamplerDesc> for SamplerDesc2 {
+    #[inline]
+    fn from(value: SamplerDesc) -> Self {
+        Self(D3D12_SAMPLER_DESC2 {
+            Filter: value.0.Filter,
+            AddressU: value.0.AddressU,
+            AddressV: value.0.AddressV,
+            AddressW: value.0.AddressW,
+            MipLODBias: value.0.MipLODBias,
+            MaxAnisotropy: value.0.MaxAnisotropy,
+            ComparisonFunc: value.0.ComparisonFunc,
+            Anonymous: D3D12_SAMPLER_DESC2_0 {
+                FloatBorderColor: value.0.BorderColor,
+            },
+            MinLOD: value.0.MinLOD,
+            MaxLOD: value.0.MaxLOD,
+            Flags: D3D12_SAMPLER_FLAG_NONE,
+        })
+    }
+}
+
+impl From<SamplerDesc2> for SamplerDesc {
+    /// Unsigned integer border colors are converted to floats, all sampler flags are dropped.
+    #[inline]
+    fn from(value: SamplerDesc2) -> Self {
+        let border_color = if value.flags().contains(SamplerFlags::UintBorderColor) {
+            unsafe { value.0.Anonymous.UintBorderColor.map(|c| c as f32) }
+        } else {
+            unsafe { value.0.Anonymous.FloatBorderColor }
+        };
+
+        Self(D3D12_SAMPLER_DESC {
+            Filter: value.0.Filter,
+            AddressU: value.0.AddressU,
+            AddressV: value.0.AddressV,
+            AddressW: value.0.AddressW,
+            MipLODBias: value.0.MipLODBias,
+            MaxAnisotropy: value.0.MaxAnisotropy,
+            ComparisonFunc: value.0.ComparisonFunc,
+            BorderColor: border_color,
+            MinLOD: value.0.MinLOD,
+            MaxLOD: value.0.MaxLOD,
+        })
+    }
+}
+
 /// Describes a shader.
 ///
 /// For more information: [`D3D12_SHADER_DESC structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12shader/ns-d3d12shader-d3d12_shader_desc)
@@ -2685,6 +3425,42 @@ impl ShaderBufferDesc {
     }
 }
 
+/// Compiled shader code, either held by a [`Blob`] or borrowed from raw bytes.
+///
+/// For more information: [`D3D12_SHADER_BYTECODE structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_shader_bytecode)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShaderBytecode<'a> {
+    Blob(&'a Blob),
+    Slice(&'a [u8]),
+}
+
+impl ShaderBytecode<'_> {
+    #[inline]
+    pub(crate) fn as_raw(&self) -> D3D12_SHADER_BYTECODE {
+        match self {
+            ShaderBytecode::Blob(blob) => blob.as_shader_bytecode(),
+            ShaderBytecode::Slice(slice) => D3D12_SHADER_BYTECODE {
+                pShaderBytecode: slice.as_ptr() as *const _,
+                BytecodeLength: slice.len(),
+            },
+        }
+    }
+}
+
+impl<'a> From<&'a Blob> for ShaderBytecode<'a> {
+    #[inline]
+    fn from(value: &'a Blob) -> Self {
+        ShaderBytecode::Blob(value)
+    }
+}
+
+impl<'a> From<&'a [u8]> for ShaderBytecode<'a> {
+    #[inline]
+    fn from(value: &'a [u8]) -> Self {
+        ShaderBytecode::Slice(value)
+    }
+}
+
 /// Defines a shader macro.
 ///
 /// For more information: [`D3D_SHADER_MACRO structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3dcommon/ns-d3dcommon-d3d_shader_macro)
@@ -2818,6 +3594,9 @@ impl ShaderResourceViewDesc {
         })
     }
 
+    /// Views `mip_levels` mips starting at `most_detailed_mip`, `u32::MAX` selects every mip down to the least detailed one.
+    ///
+    /// Raising `most_detailed_mip` or `resource_min_lod_clamp` hides the detailed mips, e.g. while they are still being streamed in.
     #[inline]
     pub fn texture_2d(
         format: Format,
@@ -2997,16 +3776,131 @@ impl ShaderResourceViewDesc {
             Shader4ComponentMapping: D3D12_DEFAULT_SHADER_4_COMPONENT_MAPPING,
         })
     }
+
+    /// Routes each of the red, green, blue and alpha components returned to the shader.
+    #[inline]
+    pub fn with_component_mapping(
+        mut self,
+        r: ShaderComponentMapping,
+        g: ShaderComponentMapping,
+        b: ShaderComponentMapping,
+        a: ShaderComponentMapping,
+    ) -> Self {
+        self.0.Shader4ComponentMapping = [r, g, b, a].iter().enumerate().fold(
+            D3D12_SHADER_COMPONENT_MAPPING_ALWAYS_SET_BIT_AVOIDING_ZEROMEM_MISTAKES,
+            |mapping, (i, c)| {
+                mapping
+                    | (c.as_raw().0 as u32 & D3D12_SHADER_COMPONENT_MAPPING_MASK)
+                        << (i as u32 * D3D12_SHADER_COMPONENT_MAPPING_SHIFT)
+            },
+        );
+        self
+    }
+
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.0.Format.into()
+    }
+
+    #[inline]
+    pub fn component_mapping(&self) -> u32 {
+        self.0.Shader4ComponentMapping
+    }
+
+    /// Returns `0` for views without a mip range, such as buffers and multisampled textures.
+    #[inline]
+    pub fn most_detailed_mip(&self) -> u32 {
+        self.mip_range().0
+    }
+
+    /// Returns `0` for views without a mip range, such as buffers and multisampled textures.
+    #[inline]
+    pub fn mip_levels(&self) -> u32 {
+        self.mip_range().1
+    }
+
+    /// Returns `0.0` for views without a mip range, such as buffers and multisampled textures.
+    #[inline]
+    pub fn resource_min_lod_clamp(&self) -> f32 {
+        self.mip_range().2
+    }
+
+    fn mip_range(&self) -> (u32, u32, f32) {
+        unsafe {
+            let view = &self.0.Anonymous;
+
+            match self.0.ViewDimension {
+                D3D12_SRV_DIMENSION_TEXTURE1D => (
+                    view.Texture1D.MostDetailedMip,
+                    view.Texture1D.MipLevels,
+                    view.Texture1D.ResourceMinLODClamp,
+                ),
+                D3D12_SRV_DIMENSION_TEXTURE1DARRAY => (
+                    view.Texture1DArray.MostDetailedMip,
+                    view.Texture1DArray.MipLevels,
+                    view.Texture1DArray.ResourceMinLODClamp,
+                ),
+                D3D12_SRV_DIMENSION_TEXTURE2D => (
+                    view.Texture2D.MostDetailedMip,
+                    view.Texture2D.MipLevels,
+                    view.Texture2D.ResourceMinLODClamp,
+                ),
+                D3D12_SRV_DIMENSION_TEXTURE2DARRAY => (
+                    view.Texture2DArray.MostDetailedMip,
+                    view.Texture2DArray.MipLevels,
+                    view.Texture2DArray.ResourceMinLODClamp,
+                ),
+                D3D12_SRV_DIMENSION_TEXTURE3D => (
+                    view.Texture3D.MostDetailedMip,
+                    view.Texture3D.MipLevels,
+                    view.Texture3D.ResourceMinLODClamp,
+                ),
+                D3D12_SRV_DIMENSION_TEXTURECUBE => (
+                    view.TextureCube.MostDetailedMip,
+                    view.TextureCube.MipLevels,
+                    view.TextureCube.ResourceMinLODClamp,
+                ),
+                D3D12_SRV_DIMENSION_TEXTURECUBEARRAY => (
+                    view.TextureCubeArray.MostDetailedMip,
+                    view.TextureCubeArray.MipLevels,
+                    view.TextureCubeArray.ResourceMinLODClamp,
+                ),
+                _ => (0, 0, 0.0),
+            }
+        }
+    }
 }
 
-/// A handle to the object of event.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// An owned handle to a shared heap, resource or fence, which is closed when dropped.
+#[derive(Debug, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct SharedHandle(pub(crate) HANDLE);
 
 impl SharedHandle {
+    /// Closes the handle, reporting the error [`Drop`] would ignore.
+    ///
+    /// For more information: [`CloseHandle function`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-closehandle)
     pub fn close(self) -> Result<(), DxError> {
-        unsafe { CloseHandle(self.0).map_err(DxError::from) }
+        unsafe { CloseHandle(self.into_raw()).map_err(DxError::from) }
+    }
+
+    #[inline]
+    pub fn as_raw(&self) -> HANDLE {
+        self.0
+    }
+
+    /// Releases ownership of the handle, which the caller must close.
+    #[inline]
+    pub fn into_raw(self) -> HANDLE {
+        ManuallyDrop::new(self).0
+    }
+}
+
+impl Drop for SharedHandle {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CloseHandle(self.0);
+        }
     }
 }
 
@@ -3257,6 +4151,75 @@ impl StaticSamplerDesc {
         self.0.ShaderVisibility = visibility.as_raw();
         self
     }
+
+    #[inline]
+    pub fn border_color(&self) -> BorderColor {
+        self.0.BorderColor.into()
+    }
+}
+
+/// Description of a state object, made of the subobjects it is composed of.
+///
+/// For more information: [`D3D12_STATE_OBJECT_DESC structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_state_object_desc)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StateObjectDesc<'a>(pub(crate) D3D12_STATE_OBJECT_DESC, PhantomData<&'a ()>);
+
+impl<'a> StateObjectDesc<'a> {
+    #[inline]
+    pub fn raytracing_pipeline(subobjects: &'a [StateSubobject<'a>]) -> Self {
+        Self(
+            D3D12_STATE_OBJECT_DESC {
+                Type: D3D12_STATE_OBJECT_TYPE_RAYTRACING_PIPELINE,
+                NumSubobjects: subobjects.len() as u32,
+                pSubobjects: subobjects.as_ptr() as *const _,
+            },
+            Default::default(),
+        )
+    }
+}
+
+/// A subobject of a state object, borrowing its description.
+///
+/// For more information: [`D3D12_STATE_SUBOBJECT structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_state_subobject)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StateSubobject<'a>(pub(crate) D3D12_STATE_SUBOBJECT, PhantomData<&'a ()>);
+
+impl<'a> StateSubobject<'a> {
+    #[inline]
+    pub fn dxil_library(desc: &'a DxilLibraryDesc<'_>) -> Self {
+        Self::new(D3D12_STATE_SUBOBJECT_TYPE_DXIL_LIBRARY, &desc.0)
+    }
+
+    #[inline]
+    pub fn global_root_signature(desc: &'a GlobalRootSignature<'_>) -> Self {
+        Self::new(D3D12_STATE_SUBOBJECT_TYPE_GLOBAL_ROOT_SIGNATURE, &desc.0)
+    }
+
+    #[inline]
+    pub fn raytracing_pipeline_config(desc: &'a RaytracingPipelineConfig) -> Self {
+        Self::new(
+            D3D12_STATE_SUBOBJECT_TYPE_RAYTRACING_PIPELINE_CONFIG,
+            &desc.0,
+        )
+    }
+
+    #[inline]
+    pub fn raytracing_shader_config(desc: &'a RaytracingShaderConfig) -> Self {
+        Self::new(D3D12_STATE_SUBOBJECT_TYPE_RAYTRACING_SHADER_CONFIG, &desc.0)
+    }
+
+    #[inline]
+    fn new<T>(r#type: D3D12_STATE_SUBOBJECT_TYPE, desc: &'a T) -> Self {
+        Self(
+            D3D12_STATE_SUBOBJECT {
+                Type: r#type,
+                pDesc: desc as *const T as *const _,
+            },
+            Default::default(),
+        )
+    }
 }
 
 /// Describes a stream output buffer.
@@ -3878,3 +4841,30 @@ impl Viewport {
         Self::from_position_and_size((0.0, 0.0), size)
     }
 }
+
+/// Specifies the immediate value and destination address written using [`IGraphicsCommandList2::write_buffer_immediate`](crate::command_list::IGraphicsCommandList2::write_buffer_immediate).
+///
+/// For more information: [`D3D12_WRITEBUFFERIMMEDIATE_PARAMETER structure`](https://learn.microsoft.com/en-us/windows/win32/api/d3d12/ns-d3d12-d3d12_writebufferimmediate_parameter)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WriteBufferImmediateParameter(pub(crate) D3D12_WRITEBUFFERIMMEDIATE_PARAMETER);
+
+impl WriteBufferImmediateParameter {
+    #[inline]
+    pub fn new(dest: GpuVirtualAddress, value: u32) -> Self {
+        Self(D3D12_WRITEBUFFERIMMEDIATE_PARAMETER {
+            Dest: dest,
+            Value: value,
+        })
+    }
+
+    #[inline]
+    pub fn dest(&self) -> GpuVirtualAddress {
+        self.0.Dest
+    }
+
+    #[inline]
+    pub fn value(&self) -> u32 {
+        self.0.Value
+    }
+}
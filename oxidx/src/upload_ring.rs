@@ -0,0 +1,232 @@
+use std::{collections::VecDeque, ptr::NonNull};
+
+use crate::{
+    device::IDevice,
+    error::DxError,
+    resources::{IResource, Resource},
+    sync::IFence,
+    types::*,
+};
+
+/// A region of an [`UploadRing`] returned by [`UploadRing::allocate`].
+///
+/// The CPU side is written through [`UploadRing::data_mut`], so several allocations can be filled in any order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UploadAllocation {
+    offset: usize,
+    size: usize,
+    gpu_va: GpuVirtualAddress,
+}
+
+impl UploadAllocation {
+    /// Offset in bytes from the start of [`UploadRing::resource`].
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline]
+    pub fn gpu_va(&self) -> GpuVirtualAddress {
+        self.gpu_va
+    }
+}
+
+/// A persistently mapped upload buffer used as a ring for transient per-frame data.
+///
+/// Memory handed out by [`UploadRing::allocate`] is reclaimed once the fence value passed to
+/// [`UploadRing::end_frame`] has been reached, see [`UploadRing::reclaim`].
+#[derive(Debug)]
+pub struct UploadRing {
+    buffer: Resource,
+    mapped: NonNull<u8>,
+    gpu_va: GpuVirtualAddress,
+    capacity: usize,
+    head: usize,
+    tail: usize,
+    frames: VecDeque<(u64, usize)>,
+}
+
+impl UploadRing {
+    /// Creates a ring of `capacity` bytes.
+    ///
+    /// Returns [`DxError::InvalidArgs`] if `capacity` is zero.
+    pub fn new(device: &impl IDevice, capacity: usize) -> Result<Self, DxError> {
+        if capacity == 0 {
+            return Err(DxError::InvalidArgs);
+        }
+
+        let buffer = device.create_committed_resource(
+            &HeapProperties::upload(),
+            HeapFlags::empty(),
+            &ResourceDesc::buffer(capacity),
+            ResourceStates::GenericRead,
+            None,
+        )?;
+
+        let mapped = buffer.map(0, Some(0..0))?;
+        let gpu_va = buffer.get_gpu_virtual_address();
+
+        Ok(Self {
+            buffer,
+            mapped,
+            gpu_va,
+            capacity,
+            head: 0,
+            tail: 0,
+            frames: VecDeque::new(),
+        })
+    }
+
+    #[inline]
+    pub fn resource(&self) -> &Resource {
+        &self.buffer
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of bytes which are still in use by frames in flight, including alignment padding.
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.head - self.tail
+    }
+
+    /// Allocates `size` bytes aligned to `alignment`, which must be a power of two.
+    /// If the allocation doesn't fit before the end of the buffer it's placed at the start.
+    ///
+    /// The mapped memory is reached through [`UploadRing::data_mut`] rather than returned here, so several
+    /// allocations of a frame can be kept and filled in any order instead of borrowing the whole ring.
+    ///
+    /// Returns [`DxError::Oom`] when the ring doesn't have enough free space.
+    pub fn allocate(&mut self, size: usize, alignment: usize) -> Result<UploadAllocation, DxError> {
+        if size > self.capacity || !alignment.is_power_of_two() {
+            return Err(DxError::InvalidArgs);
+        }
+
+        let offset = self.head % self.capacity;
+        let mut aligned = (offset + alignment - 1) & !(alignment - 1);
+
+        if aligned + size > self.capacity {
+            aligned = self.capacity;
+        }
+
+        let padding = aligned - offset;
+
+        if self.used() + padding + size > self.capacity {
+            return Err(DxError::Oom);
+        }
+
+        self.head += padding + size;
+        let offset = aligned % self.capacity;
+
+        Ok(UploadAllocation {
+            offset,
+            size,
+            gpu_va: self.gpu_va + offset as u64,
+        })
+    }
+
+    /// Mapped memory of `allocation`, which must have been returned by this ring and not yet reclaimed.
+    ///
+    /// # Panics
+    /// If `allocation` doesn't lie within the ring.
+    pub fn data_mut(&mut self, allocation: &UploadAllocation) -> &mut [u8] {
+        assert!(
+            allocation
+                .offset
+                .checked_add(allocation.size)
+                .is_some_and(|end| end <= self.capacity),
+            "allocation is out of the ring bounds"
+        );
+
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.mapped.as_ptr().add(allocation.offset),
+                allocation.size,
+            )
+        }
+    }
+
+    /// Marks the end of the current frame. Its allocations are reclaimed once `fence_value` is completed.
+    #[inline]
+    pub fn end_frame(&mut self, fence_value: u64) {
+        self.frames.push_back((fence_value, self.head));
+    }
+
+    /// Frees allocations of all frames whose fence value has been completed.
+    pub fn reclaim(&mut self, fence: &impl IFence) {
+        let completed = fence.get_completed_value();
+
+        while let Some(&(value, head)) = self.frames.front() {
+            if value > completed {
+                break;
+            }
+
+            self.tail = head;
+            self.frames.pop_front();
+        }
+    }
+}
+
+impl Drop for UploadRing {
+    fn drop(&mut self) {
+        self.buffer.unmap(0, None);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn upload_ring_wrap_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+
+        let alignment = CONSTANT_BUFFER_DATA_PLACEMENT_ALIGNMENT as usize;
+        let mut ring = UploadRing::new(&device, 1024).unwrap();
+        let base = ring.resource().get_gpu_virtual_address();
+        let end = base + ring.capacity() as u64;
+
+        let mut allocations = vec![];
+        for _ in 0..3 {
+            allocations.push(ring.allocate(200, alignment).unwrap());
+        }
+        ring.end_frame(1);
+
+        assert!(ring.allocate(512, alignment).is_err());
+
+        fence.signal(1).unwrap();
+        ring.reclaim(&fence);
+
+        // The head is at 712, so 512 bytes don't fit before the end and wrap to the start.
+        let first = ring.allocate(512, alignment).unwrap();
+        let second = ring.allocate(128, alignment).unwrap();
+
+        assert_eq!(first.gpu_va(), base);
+        assert_eq!(second.gpu_va(), base + 512);
+
+        allocations.extend([first, second]);
+        for allocation in &allocations {
+            assert!((base..end).contains(&allocation.gpu_va()));
+            assert!(allocation.gpu_va() + allocation.size() as u64 <= end);
+        }
+
+        ring.data_mut(&first).fill(1);
+        ring.data_mut(&second).fill(2);
+        assert!(ring.data_mut(&first).iter().all(|&b| b == 1));
+        assert_eq!(ring.data_mut(&second).len(), 128);
+
+        assert!(matches!(
+            UploadRing::new(&device, 0),
+            Err(DxError::InvalidArgs)
+        ));
+    }
+}
@@ -30,6 +30,23 @@ macro_rules! create_type {
             }
         }
 
+        #[cfg(feature = "debug-refcount")]
+        impl $name {
+            /// Returns the current reference count of the underlying COM object by calling `AddRef` followed by `Release`.
+            ///
+            /// For leak diagnostics only: the value is a snapshot and may already be stale
+            /// if other threads hold references to the same object.
+            pub fn debug_ref_count(&self) -> u32 {
+                unsafe {
+                    let this = ::windows::core::Interface::as_raw(&self.0);
+                    let vtable = *(this as *const *const ::windows::core::IUnknown_Vtbl);
+
+                    ((*vtable).AddRef)(this);
+                    ((*vtable).Release)(this)
+                }
+            }
+        }
+
         $(
             /// Upcast
             impl From<$name> for $base {
@@ -147,3 +164,59 @@ macro_rules! impl_up_down_cast {
         }
     };
 }
+
+#[cfg(all(test, feature = "debug-refcount"))]
+mod test {
+    use crate::dx::*;
+
+    #[test]
+    fn debug_ref_count_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let fence = device.create_fence(0, FenceFlags::empty()).unwrap();
+
+        let before = fence.debug_ref_count();
+        let cloned = fence.clone();
+
+        assert_eq!(fence.debug_ref_count(), before + 1);
+        drop(cloned);
+        assert_eq!(fence.debug_ref_count(), before);
+    }
+
+    #[test]
+    fn resource_barrier_ref_count_test() {
+        let device = create_device(ADAPTER_NONE, FeatureLevel::Level11).unwrap();
+        let create_buffer = || {
+            device
+                .create_committed_resource(
+                    &HeapProperties::default(),
+                    HeapFlags::empty(),
+                    &ResourceDesc::buffer(256).with_flags(ResourceFlags::AllowUnorderedAccess),
+                    ResourceStates::Common,
+                    None,
+                )
+                .unwrap()
+        };
+        let first = create_buffer();
+        let second = create_buffer();
+
+        let before = (first.debug_ref_count(), second.debug_ref_count());
+
+        {
+            let barriers = [
+                ResourceBarrier::transition(
+                    &first,
+                    ResourceStates::Common,
+                    ResourceStates::CopyDest,
+                    None,
+                ),
+                ResourceBarrier::aliasing(&first, &second),
+                ResourceBarrier::uav(&second),
+            ];
+            let _cloned = barriers.clone();
+
+            assert_eq!((first.debug_ref_count(), second.debug_ref_count()), before);
+        }
+
+        assert_eq!((first.debug_ref_count(), second.debug_ref_count()), before);
+    }
+}